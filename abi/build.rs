@@ -6,7 +6,10 @@ fn main() {
     tonic_build::configure()
         .out_dir("src/pb")
         .with_sqlx_type(&["reservation.ReservationStatus"])
-        .with_derive_builder(&["reservation.ReservationQuery"])
+        .with_derive_builder(&[
+            "reservation.ReservationQuery",
+            "reservation.AvailabilityQuery",
+        ])
         .with_derive_builder_into(
             "reservation.ReservationQuery",
             &[
@@ -16,9 +19,22 @@ fn main() {
                 "page",
                 "desc",
                 "page_size",
+                "cursor",
+                "mode",
+                "statuses",
+                "resource_ids",
+                "user_ids",
+                "note_search",
+                "attributes",
             ],
         )
         .with_derive_builder_option("reservation.ReservationQuery", &["start", "end"])
+        .with_derive_builder_into("reservation.AvailabilityQuery", &["resource_ids"])
+        .with_derive_builder_option("reservation.AvailabilityQuery", &["start", "end"])
+        .field_attribute(
+            "reservation.AvailabilityQuery.min_duration",
+            "#[builder(setter(into, strip_option), default)]",
+        )
         .compile_protos(&["protos/reservation.proto"], &["proto"])
         .unwrap();
 
@@ -2,6 +2,8 @@ mod conflict;
 
 use sqlx::postgres::PgDatabaseError;
 
+use crate::ReservationStatus;
+
 pub use conflict::{ReservationConflict, ReservationConflictInfo, ReservationWindow};
 
 #[derive(thiserror::Error, Debug)]
@@ -12,6 +14,21 @@ pub enum Error {
     #[error("Invalid start or end time")]
     InvalidTime,
 
+    #[error("Invalid page cursor: {0}")]
+    InvalidCursor(String),
+
+    #[error("Invalid page: {0}, it must not be negative")]
+    InvalidPage(i32),
+
+    #[error("Invalid page size: {0}, it must be between 1 and 100")]
+    InvalidPageSize(i32),
+
+    #[error("Invalid reservation status: {0}")]
+    InvalidStatus(i32),
+
+    #[error("Invalid minimum duration, it must not be negative")]
+    InvalidMinDuration,
+
     #[error("Conflict reservation")]
     ConflictReservation(ReservationConflictInfo),
 
@@ -27,6 +44,39 @@ pub enum Error {
     #[error("Invalid resource id: {0}")]
     InvalidResourceId(String),
 
+    #[error("Resource {0} does not exist or is inactive")]
+    UnavailableResource(String),
+
+    #[error("No resource of the group is available for the window")]
+    NoAvailableResource,
+
+    #[error("Invalid capacity: {0}")]
+    InvalidCapacity(i32),
+
+    #[error("Resource {0} already exists")]
+    DuplicateResource(String),
+
+    #[error("Invalid hold ttl or expiration, it must be positive and in range")]
+    InvalidHoldTtl,
+
+    #[error("Invalid recurrence rule: {0}")]
+    InvalidRecurrenceRule(String),
+
+    #[error("Invalid status transition from {current} to {requested}")]
+    InvalidTransition {
+        current: ReservationStatus,
+        requested: ReservationStatus,
+    },
+
+    #[error("Reservation version mismatch, expected {expected} but it is {actual}")]
+    VersionMismatch { expected: i64, actual: i64 },
+
+    #[error("Failed to read configuration file")]
+    ConfigReadError,
+
+    #[error("Failed to parse configuration file")]
+    ConfigParseError,
+
     #[error("unknown error")]
     Unknown,
 }
@@ -55,13 +105,78 @@ impl PartialEq for Error {
             // TODO: this is not a good way to compare DB errors, but we don't do that in the code
             (Self::DbError(_), Self::DbError(_)) => true,
             (Self::InvalidTime, Self::InvalidTime) => true,
+            (Self::InvalidMinDuration, Self::InvalidMinDuration) => true,
+            (Self::InvalidCursor(v1), Self::InvalidCursor(v2)) => v1 == v2,
+            (Self::InvalidPage(v1), Self::InvalidPage(v2)) => v1 == v2,
+            (Self::InvalidPageSize(v1), Self::InvalidPageSize(v2)) => v1 == v2,
+            (Self::InvalidStatus(v1), Self::InvalidStatus(v2)) => v1 == v2,
             (Self::ConflictReservation(v1), Self::ConflictReservation(v2)) => v1 == v2,
             (Self::NotFound, Self::NotFound) => true,
             (Self::InvalidReservationId(v1), Self::InvalidReservationId(v2)) => v1 == v2,
             (Self::InvalidUserId(v1), Self::InvalidUserId(v2)) => v1 == v2,
             (Self::InvalidResourceId(v1), Self::InvalidResourceId(v2)) => v1 == v2,
+            (Self::UnavailableResource(v1), Self::UnavailableResource(v2)) => v1 == v2,
+            (Self::DuplicateResource(v1), Self::DuplicateResource(v2)) => v1 == v2,
+            (Self::InvalidCapacity(v1), Self::InvalidCapacity(v2)) => v1 == v2,
+            (Self::NoAvailableResource, Self::NoAvailableResource) => true,
+            (Self::InvalidHoldTtl, Self::InvalidHoldTtl) => true,
+            (Self::InvalidRecurrenceRule(v1), Self::InvalidRecurrenceRule(v2)) => v1 == v2,
+            (
+                Self::InvalidTransition {
+                    current: c1,
+                    requested: r1,
+                },
+                Self::InvalidTransition {
+                    current: c2,
+                    requested: r2,
+                },
+            ) => c1 == c2 && r1 == r2,
+            (
+                Self::VersionMismatch {
+                    expected: e1,
+                    actual: a1,
+                },
+                Self::VersionMismatch {
+                    expected: e2,
+                    actual: a2,
+                },
+            ) => e1 == e2 && a1 == a2,
+            (Self::ConfigReadError, Self::ConfigReadError) => true,
+            (Self::ConfigParseError, Self::ConfigParseError) => true,
             (Self::Unknown, Self::Unknown) => true,
             _ => false,
         }
     }
 }
+
+impl From<Error> for tonic::Status {
+    fn from(e: Error) -> Self {
+        match e {
+            Error::DbError(_) | Error::ConfigReadError | Error::ConfigParseError => {
+                tonic::Status::internal(e.to_string())
+            }
+            Error::InvalidTime
+            | Error::InvalidMinDuration
+            | Error::InvalidCursor(_)
+            | Error::InvalidPage(_)
+            | Error::InvalidPageSize(_)
+            | Error::InvalidStatus(_)
+            | Error::InvalidReservationId(_)
+            | Error::InvalidUserId(_)
+            | Error::InvalidResourceId(_)
+            | Error::InvalidCapacity(_)
+            | Error::InvalidHoldTtl
+            | Error::InvalidRecurrenceRule(_) => tonic::Status::invalid_argument(e.to_string()),
+            Error::InvalidTransition { .. }
+            | Error::UnavailableResource(_)
+            | Error::NoAvailableResource => tonic::Status::failed_precondition(e.to_string()),
+            Error::DuplicateResource(_) => tonic::Status::already_exists(e.to_string()),
+            Error::ConflictReservation(info) => {
+                tonic::Status::failed_precondition(format!("Conflict reservation: {info:?}"))
+            }
+            Error::VersionMismatch { .. } => tonic::Status::aborted(e.to_string()),
+            Error::NotFound => tonic::Status::not_found(e.to_string()),
+            Error::Unknown => tonic::Status::unknown(e.to_string()),
+        }
+    }
+}
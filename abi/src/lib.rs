@@ -1,10 +1,15 @@
+mod config;
 mod error;
 mod pb;
+mod recurrence;
 mod types;
 mod utils;
 
+pub use config::{Config, DbConfig, ServerConfig};
 pub use error::{Error, ReservationConflict, ReservationConflictInfo, ReservationWindow};
 pub use pb::*;
+pub use recurrence::{Frequency, MAX_OCCURRENCES, Occurrence, RecurrenceRule};
+pub use types::ReservationCursor;
 
 pub use utils::*;
 
@@ -38,6 +43,17 @@ pub enum RsvpStatus {
     Pending,
     Confirmed,
     Blocked,
+    Cancelled,
+}
+
+/// database equivalent of the "reservation_update_type" enum
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "rsvp.reservation_update_type", rename_all = "lowercase")]
+pub enum RsvpUpdateType {
+    Unknown,
+    Create,
+    Update,
+    Delete,
 }
 
 impl Validator for ReservationId {
@@ -1,6 +1,7 @@
 // This file is @generated by prost-build.
 /// Core reservation object. Contains all the information for a reservation.
-/// If SubscribeResponse.op is RESERVATION_UPDATE_TYPE_DELETE, only id will be populated.
+/// In a SubscribeResponse it holds the reservation as of the change, for
+/// RESERVATION_UPDATE_TYPE_DELETE that is the reservation which was deleted.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Reservation {
     /// unique id for the reservation
@@ -20,22 +21,105 @@ pub struct Reservation {
     /// extra note
     #[prost(string, tag = "7")]
     pub note: ::prost::alloc::string::String,
+    /// a pending reservation is cancelled automatically once expired
+    #[prost(message, optional, tag = "8")]
+    pub expires_at: ::core::option::Option<::prost_types::Timestamp>,
+    /// recurring reservation series the reservation belongs to, 0 if not recurring
+    #[prost(int64, tag = "9")]
+    pub series_id: i64,
+    /// structured metadata, e.g. {"guests": "2", "source": "web"}
+    #[prost(map = "string, string", tag = "10")]
+    pub attributes:
+        ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+    /// incremented on every update, starting at 1
+    #[prost(int64, tag = "11")]
+    pub version: i64,
+    /// user who cancelled the reservation, empty if it isn't cancelled or its hold expired
+    #[prost(string, tag = "12")]
+    pub cancelled_by: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "13")]
+    pub cancelled_at: ::core::option::Option<::prost_types::Timestamp>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ReserveRequest {
     #[prost(message, optional, tag = "1")]
     pub reservation: ::core::option::Option<Reservation>,
+    /// hold the window for this long, the reservation is cancelled unless confirmed in time
+    #[prost(message, optional, tag = "2")]
+    pub hold_ttl: ::core::option::Option<::prost_types::Duration>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ReserveResponse {
     #[prost(message, optional, tag = "1")]
     pub reservation: ::core::option::Option<Reservation>,
 }
-/// To change status from Pending to Confirmed
+/// Reserve several resources at once, either all of them are reserved or none
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReserveManyRequest {
+    #[prost(message, repeated, tag = "1")]
+    pub reservations: ::prost::alloc::vec::Vec<Reservation>,
+    /// hold the windows for this long, the reservations are cancelled unless confirmed in time
+    #[prost(message, optional, tag = "2")]
+    pub hold_ttl: ::core::option::Option<::prost_types::Duration>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReserveManyResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub reservations: ::prost::alloc::vec::Vec<Reservation>,
+}
+/// Reserve every occurrence of a recurring reservation. The given reservation is the
+/// first occurrence, the following ones are expanded from the recurrence rule
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReserveRecurringRequest {
+    #[prost(message, optional, tag = "1")]
+    pub reservation: ::core::option::Option<Reservation>,
+    /// RRULE subset: FREQ (DAILY or WEEKLY), INTERVAL, COUNT, UNTIL and BYDAY, e.g.
+    /// "FREQ=WEEKLY;BYDAY=TU;COUNT=12". Either COUNT or UNTIL is required, the rule
+    /// is evaluated in UTC
+    #[prost(string, tag = "2")]
+    pub rrule: ::prost::alloc::string::String,
+    /// reserve the free occurrences even if some conflict, otherwise none is reserved
+    #[prost(bool, tag = "3")]
+    pub allow_partial: bool,
+    /// hold the windows for this long, the reservations are cancelled unless confirmed in time
+    #[prost(message, optional, tag = "4")]
+    pub hold_ttl: ::core::option::Option<::prost_types::Duration>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReserveRecurringResponse {
+    /// reserved occurrences, empty if any conflicts and allow_partial is not set
+    #[prost(message, repeated, tag = "1")]
+    pub reservations: ::prost::alloc::vec::Vec<Reservation>,
+    /// occurrences that conflict with existing reservations
+    #[prost(message, repeated, tag = "2")]
+    pub conflicts: ::prost::alloc::vec::Vec<Reservation>,
+}
+/// Block a resource for a time window, e.g. for maintenance or cleaning.
+/// The reservation is created with status BLOCKED regardless of the given status
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BlockRequest {
+    #[prost(message, optional, tag = "1")]
+    pub reservation: ::core::option::Option<Reservation>,
+    /// cancel the pending reservations overlapping the window instead of failing
+    #[prost(bool, tag = "2")]
+    pub evict_pending: bool,
+}
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BlockResponse {
+    #[prost(message, optional, tag = "1")]
+    pub reservation: ::core::option::Option<Reservation>,
+    /// pending reservations cancelled to make room for the block
+    #[prost(message, repeated, tag = "2")]
+    pub evicted: ::prost::alloc::vec::Vec<Reservation>,
+}
+/// To change status from Pending to Confirmed
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct ConfirmRequest {
-    #[prost(string, tag = "1")]
-    pub id: ::prost::alloc::string::String,
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    /// fail with ABORTED unless the reservation is at this version, 0 skips the check
+    #[prost(int64, tag = "2")]
+    pub expected_version: i64,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ConfirmResponse {
@@ -45,34 +129,284 @@ pub struct ConfirmResponse {
 /// Only note can be updated
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdateRequest {
-    #[prost(string, tag = "1")]
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    #[prost(string, tag = "2")]
     pub note: ::prost::alloc::string::String,
+    #[prost(enumeration = "SeriesScope", tag = "3")]
+    pub scope: i32,
+    /// fail with ABORTED unless the reservation is at this version, 0 skips the check
+    #[prost(int64, tag = "4")]
+    pub expected_version: i64,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdateResponse {
     #[prost(message, optional, tag = "1")]
     pub reservation: ::core::option::Option<Reservation>,
+    /// every updated reservation, including the one given by id
+    #[prost(message, repeated, tag = "2")]
+    pub reservations: ::prost::alloc::vec::Vec<Reservation>,
 }
+/// Move a reservation to another window, and optionally another resource, keeping its id
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RescheduleRequest {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    #[prost(message, optional, tag = "2")]
+    pub start: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "3")]
+    pub end: ::core::option::Option<::prost_types::Timestamp>,
+    /// resource to move the reservation to, the current one if empty
+    #[prost(string, tag = "4")]
+    pub resource_id: ::prost::alloc::string::String,
+    /// fail with ABORTED unless the reservation is at this version, 0 skips the check
+    #[prost(int64, tag = "5")]
+    pub expected_version: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RescheduleResponse {
+    #[prost(message, optional, tag = "1")]
+    pub reservation: ::core::option::Option<Reservation>,
+}
+/// Exchange the resources of two reservations, e.g. two guests trading rooms
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SwapRequest {
+    #[prost(int64, tag = "1")]
+    pub id_a: i64,
+    #[prost(int64, tag = "2")]
+    pub id_b: i64,
+    /// exchange the windows as well
+    #[prost(bool, tag = "3")]
+    pub swap_windows: bool,
+    /// fail with ABORTED unless the reservations are at these versions, 0 skips the check
+    #[prost(int64, tag = "4")]
+    pub expected_version_a: i64,
+    #[prost(int64, tag = "5")]
+    pub expected_version_b: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SwapResponse {
+    #[prost(message, optional, tag = "1")]
+    pub reservation_a: ::core::option::Option<Reservation>,
+    #[prost(message, optional, tag = "2")]
+    pub reservation_b: ::core::option::Option<Reservation>,
+}
+/// Patch the attributes of a reservation, the other attributes are kept
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateAttributesRequest {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    /// attributes to add or overwrite
+    #[prost(map = "string, string", tag = "2")]
+    pub set:
+        ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+    /// keys of the attributes to remove, removed before set is applied
+    #[prost(string, repeated, tag = "3")]
+    pub remove: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// fail with ABORTED unless the reservation is at this version, 0 skips the check
+    #[prost(int64, tag = "4")]
+    pub expected_version: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateAttributesResponse {
+    #[prost(message, optional, tag = "1")]
+    pub reservation: ::core::option::Option<Reservation>,
+}
+/// To change status to Cancelled, the reservation is kept but frees its slot
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CancelRequest {
-    #[prost(string, tag = "1")]
-    pub id: ::prost::alloc::string::String,
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    #[prost(enumeration = "SeriesScope", tag = "2")]
+    pub scope: i32,
+    /// fail with ABORTED unless the reservation is at this version, 0 skips the check
+    #[prost(int64, tag = "3")]
+    pub expected_version: i64,
+    /// user cancelling the reservation
+    #[prost(string, tag = "4")]
+    pub user_id: ::prost::alloc::string::String,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CancelResponse {
     #[prost(message, optional, tag = "1")]
     pub reservation: ::core::option::Option<Reservation>,
+    /// every cancelled reservation, including the one given by id
+    #[prost(message, repeated, tag = "2")]
+    pub reservations: ::prost::alloc::vec::Vec<Reservation>,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct GetRequest {
-    #[prost(string, tag = "1")]
-    pub id: ::prost::alloc::string::String,
+    #[prost(int64, tag = "1")]
+    pub id: i64,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetResponse {
     #[prost(message, optional, tag = "1")]
     pub reservation: ::core::option::Option<Reservation>,
 }
+/// Search the free intervals of resources within a window
+#[derive(derive_builder::Builder, Clone, PartialEq, ::prost::Message)]
+pub struct AvailabilityQuery {
+    #[prost(string, repeated, tag = "1")]
+    #[builder(setter(into), default)]
+    pub resource_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(message, optional, tag = "2")]
+    #[builder(setter(into, strip_option))]
+    pub start: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "3")]
+    #[builder(setter(into, strip_option))]
+    pub end: ::core::option::Option<::prost_types::Timestamp>,
+    /// only return free intervals at least this long
+    #[prost(message, optional, tag = "4")]
+    #[builder(setter(into, strip_option), default)]
+    pub min_duration: ::core::option::Option<::prost_types::Duration>,
+}
+/// A window in which the resource can be reserved. For a pooled resource at least one
+/// reservation more fits in the window
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FreeInterval {
+    #[prost(string, tag = "1")]
+    pub resource_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub start: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "3")]
+    pub end: ::core::option::Option<::prost_types::Timestamp>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AvailabilityRequest {
+    #[prost(message, optional, tag = "1")]
+    pub query: ::core::option::Option<AvailabilityQuery>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AvailabilityResponse {
+    /// free intervals ordered by resource id and start
+    #[prost(message, repeated, tag = "1")]
+    pub intervals: ::prost::alloc::vec::Vec<FreeInterval>,
+}
+/// Resources that are interchangeable for a reservation: the given resource ids, in order of
+/// preference, or every active resource of a kind
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResourceGroup {
+    #[prost(string, repeated, tag = "1")]
+    pub resource_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, tag = "2")]
+    pub kind: ::prost::alloc::string::String,
+}
+/// Find the resources of a group that can be reserved for the whole window
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FindAvailableQuery {
+    #[prost(message, optional, tag = "1")]
+    pub group: ::core::option::Option<ResourceGroup>,
+    #[prost(message, optional, tag = "2")]
+    pub start: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "3")]
+    pub end: ::core::option::Option<::prost_types::Timestamp>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FindAvailableRequest {
+    #[prost(message, optional, tag = "1")]
+    pub query: ::core::option::Option<FindAvailableQuery>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FindAvailableResponse {
+    /// available resources in order of preference, then by id
+    #[prost(string, repeated, tag = "1")]
+    pub resource_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// Reserve the first available resource of a group, resource_id of the reservation is ignored
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReserveAnyRequest {
+    #[prost(message, optional, tag = "1")]
+    pub group: ::core::option::Option<ResourceGroup>,
+    #[prost(message, optional, tag = "2")]
+    pub reservation: ::core::option::Option<Reservation>,
+    /// hold the window for this long, the reservation is cancelled unless confirmed in time
+    #[prost(message, optional, tag = "3")]
+    pub hold_ttl: ::core::option::Option<::prost_types::Duration>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReserveAnyResponse {
+    #[prost(message, optional, tag = "1")]
+    pub reservation: ::core::option::Option<Reservation>,
+}
+/// A reservable resource, e.g. a room or a parking spot
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Resource {
+    /// unique id, referenced by reservations as resource_id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    /// free-form category, e.g. "room"
+    #[prost(string, tag = "3")]
+    pub kind: ::prost::alloc::string::String,
+    #[prost(map = "string, string", tag = "4")]
+    pub attributes:
+        ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+    /// inactive resources can't be reserved
+    #[prost(bool, tag = "5")]
+    pub active: bool,
+    /// number of reservations admitted at the same time, e.g. the spots of a parking lot.
+    /// 0 is the same as 1. Lowering it doesn't affect existing reservations
+    #[prost(int32, tag = "6")]
+    pub capacity: i32,
+}
+/// The resource is created active
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateResourceRequest {
+    #[prost(message, optional, tag = "1")]
+    pub resource: ::core::option::Option<Resource>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateResourceResponse {
+    #[prost(message, optional, tag = "1")]
+    pub resource: ::core::option::Option<Resource>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetResourceRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetResourceResponse {
+    #[prost(message, optional, tag = "1")]
+    pub resource: ::core::option::Option<Resource>,
+}
+/// Update name, kind, attributes and capacity of the resource with the given id
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateResourceRequest {
+    #[prost(message, optional, tag = "1")]
+    pub resource: ::core::option::Option<Resource>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateResourceResponse {
+    #[prost(message, optional, tag = "1")]
+    pub resource: ::core::option::Option<Resource>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListResourcesRequest {
+    /// only list resources of this kind, all kinds if empty
+    #[prost(string, tag = "1")]
+    pub kind: ::prost::alloc::string::String,
+    #[prost(bool, tag = "2")]
+    pub include_inactive: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListResourcesResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub resources: ::prost::alloc::vec::Vec<Resource>,
+}
+/// Existing reservations are kept, but the resource can no longer be reserved
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeactivateResourceRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeactivateResourceResponse {
+    #[prost(message, optional, tag = "1")]
+    pub resource: ::core::option::Option<Resource>,
+}
 /// query reservations with user id, resource id, status, start time, end time
 #[derive(derive_builder::Builder, Clone, PartialEq, ::prost::Message)]
 pub struct ReservationQuery {
@@ -96,7 +430,7 @@ pub struct ReservationQuery {
     #[prost(int32, tag = "6")]
     #[builder(setter(into), default)]
     pub page: i32,
-    /// page size for the query
+    /// page size for the query, between 1 and 100, 10 if unset
     #[prost(int32, tag = "7")]
     #[builder(setter(into), default)]
     pub page_size: i32,
@@ -104,6 +438,43 @@ pub struct ReservationQuery {
     #[prost(bool, tag = "8")]
     #[builder(setter(into), default)]
     pub desc: bool,
+    /// continue after the page that returned this cursor, page is ignored when set
+    #[prost(string, tag = "9")]
+    #[builder(setter(into), default)]
+    pub cursor: ::prost::alloc::string::String,
+    #[prost(enumeration = "QueryMatchMode", tag = "10")]
+    #[builder(setter(into), default)]
+    pub mode: i32,
+    /// match any of these statuses, takes precedence over status when not empty
+    #[prost(enumeration = "ReservationStatus", repeated, tag = "11")]
+    #[builder(setter(into), default)]
+    pub statuses: ::prost::alloc::vec::Vec<i32>,
+    /// match any of these resources, in addition to resource_id
+    #[prost(string, repeated, tag = "12")]
+    #[builder(setter(into), default)]
+    pub resource_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// match any of these users, in addition to user_id
+    #[prost(string, repeated, tag = "13")]
+    #[builder(setter(into), default)]
+    pub user_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// full-text search of the note, e.g. "crib" or "late -vip"
+    #[prost(string, tag = "14")]
+    #[builder(setter(into), default)]
+    pub note_search: ::prost::alloc::string::String,
+    /// match reservations having all of these attributes
+    #[prost(map = "string, string", tag = "15")]
+    #[builder(setter(into), default)]
+    pub attributes:
+        ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+}
+/// A page of reservations ordered by start time and id
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReservationPage {
+    #[prost(message, repeated, tag = "1")]
+    pub reservations: ::prost::alloc::vec::Vec<Reservation>,
+    /// cursor of the next page, empty if this is the last one
+    #[prost(string, tag = "2")]
+    pub next_cursor: ::prost::alloc::string::String,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryRequest {
@@ -112,13 +483,21 @@ pub struct QueryRequest {
 }
 /// Client subscribe to reservation updates
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
-pub struct SubscribeRequest {}
+pub struct SubscribeRequest {
+    /// replay every change after this change id before streaming live changes.
+    /// if not set, only changes made after subscribing will be returned
+    #[prost(int64, optional, tag = "1")]
+    pub since_change_id: ::core::option::Option<i64>,
+}
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SubscribeResponse {
     #[prost(enumeration = "ReservationUpdateType", tag = "1")]
     pub op: i32,
     #[prost(message, optional, tag = "2")]
     pub reservation: ::core::option::Option<Reservation>,
+    /// id of the change, can be used as since_change_id to resume the subscription
+    #[prost(int64, tag = "3")]
+    pub change_id: i64,
 }
 #[derive(
     sqlx::Type, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration,
@@ -129,6 +508,7 @@ pub enum ReservationStatus {
     Pending = 1,
     Confirmed = 2,
     Blocked = 3,
+    Cancelled = 4,
 }
 impl ReservationStatus {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -141,6 +521,7 @@ impl ReservationStatus {
             Self::Pending => "RESERVATION_STATUS_PENDING",
             Self::Confirmed => "RESERVATION_STATUS_CONFIRMED",
             Self::Blocked => "RESERVATION_STATUS_BLOCKED",
+            Self::Cancelled => "RESERVATION_STATUS_CANCELLED",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -150,6 +531,7 @@ impl ReservationStatus {
             "RESERVATION_STATUS_PENDING" => Some(Self::Pending),
             "RESERVATION_STATUS_CONFIRMED" => Some(Self::Confirmed),
             "RESERVATION_STATUS_BLOCKED" => Some(Self::Blocked),
+            "RESERVATION_STATUS_CANCELLED" => Some(Self::Cancelled),
             _ => None,
         }
     }
@@ -186,6 +568,72 @@ impl ReservationUpdateType {
         }
     }
 }
+/// which occurrences of a recurring reservation an operation applies to
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SeriesScope {
+    /// only the given reservation
+    Occurrence = 0,
+    /// the given reservation and every later occurrence of its series
+    Following = 1,
+    /// every occurrence of the series
+    All = 2,
+}
+impl SeriesScope {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Occurrence => "SERIES_SCOPE_OCCURRENCE",
+            Self::Following => "SERIES_SCOPE_FOLLOWING",
+            Self::All => "SERIES_SCOPE_ALL",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SERIES_SCOPE_OCCURRENCE" => Some(Self::Occurrence),
+            "SERIES_SCOPE_FOLLOWING" => Some(Self::Following),
+            "SERIES_SCOPE_ALL" => Some(Self::All),
+            _ => None,
+        }
+    }
+}
+/// how reservations are matched against the query window
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum QueryMatchMode {
+    /// the reservation lies within the window
+    Contained = 0,
+    /// the reservation shares any time with the window, e.g. who is in the room today
+    Overlaps = 1,
+    /// the reservation starts within the window
+    StartsWithin = 2,
+}
+impl QueryMatchMode {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Contained => "QUERY_MATCH_MODE_CONTAINED",
+            Self::Overlaps => "QUERY_MATCH_MODE_OVERLAPS",
+            Self::StartsWithin => "QUERY_MATCH_MODE_STARTS_WITHIN",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "QUERY_MATCH_MODE_CONTAINED" => Some(Self::Contained),
+            "QUERY_MATCH_MODE_OVERLAPS" => Some(Self::Overlaps),
+            "QUERY_MATCH_MODE_STARTS_WITHIN" => Some(Self::StartsWithin),
+            _ => None,
+        }
+    }
+}
 /// Generated client implementations.
 pub mod reservation_service_client {
     #![allow(
@@ -291,6 +739,59 @@ pub mod reservation_service_client {
                 .insert(GrpcMethod::new("reservation.ReservationService", "reserve"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn reserve_many(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ReserveManyRequest>,
+        ) -> std::result::Result<tonic::Response<super::ReserveManyResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/reserve_many",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "reserve_many",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn reserve_recurring(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ReserveRecurringRequest>,
+        ) -> std::result::Result<tonic::Response<super::ReserveRecurringResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/reserve_recurring",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "reserve_recurring",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn block(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BlockRequest>,
+        ) -> std::result::Result<tonic::Response<super::BlockResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/block");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("reservation.ReservationService", "block"));
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn confirm(
             &mut self,
             request: impl tonic::IntoRequest<super::ConfirmRequest>,
@@ -321,58 +822,182 @@ pub mod reservation_service_client {
                 .insert(GrpcMethod::new("reservation.ReservationService", "update"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn cancel(
+        pub async fn reschedule(
             &mut self,
-            request: impl tonic::IntoRequest<super::CancelRequest>,
-        ) -> std::result::Result<tonic::Response<super::CancelResponse>, tonic::Status> {
+            request: impl tonic::IntoRequest<super::RescheduleRequest>,
+        ) -> std::result::Result<tonic::Response<super::RescheduleResponse>, tonic::Status>
+        {
             self.inner.ready().await.map_err(|e| {
                 tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
             })?;
             let codec = tonic::codec::ProstCodec::default();
             let path =
-                http::uri::PathAndQuery::from_static("/reservation.ReservationService/cancel");
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/reschedule");
             let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("reservation.ReservationService", "cancel"));
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "reschedule",
+            ));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn get(
+        pub async fn swap(
             &mut self,
-            request: impl tonic::IntoRequest<super::Reservation>,
-        ) -> std::result::Result<tonic::Response<super::Reservation>, tonic::Status> {
+            request: impl tonic::IntoRequest<super::SwapRequest>,
+        ) -> std::result::Result<tonic::Response<super::SwapResponse>, tonic::Status> {
             self.inner.ready().await.map_err(|e| {
                 tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
             })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static("/reservation.ReservationService/get");
+            let path = http::uri::PathAndQuery::from_static("/reservation.ReservationService/swap");
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("reservation.ReservationService", "get"));
+                .insert(GrpcMethod::new("reservation.ReservationService", "swap"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn query(
+        pub async fn update_attributes(
             &mut self,
-            request: impl tonic::IntoRequest<super::QueryRequest>,
-        ) -> std::result::Result<
-            tonic::Response<tonic::codec::Streaming<super::Reservation>>,
-            tonic::Status,
-        > {
+            request: impl tonic::IntoRequest<super::UpdateAttributesRequest>,
+        ) -> std::result::Result<tonic::Response<super::UpdateAttributesResponse>, tonic::Status>
+        {
             self.inner.ready().await.map_err(|e| {
                 tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
             })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path =
-                http::uri::PathAndQuery::from_static("/reservation.ReservationService/query");
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/update_attributes",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "update_attributes",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn cancel(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CancelRequest>,
+        ) -> std::result::Result<tonic::Response<super::CancelResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/cancel");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("reservation.ReservationService", "cancel"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/reservation.ReservationService/get");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("reservation.ReservationService", "get"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn query(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::Reservation>>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/query");
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("reservation.ReservationService", "query"));
             self.inner.server_streaming(req, path, codec).await
         }
+        pub async fn query_page(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryRequest>,
+        ) -> std::result::Result<tonic::Response<super::ReservationPage>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/query_page");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "query_page",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn availability(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AvailabilityRequest>,
+        ) -> std::result::Result<tonic::Response<super::AvailabilityResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/availability",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "availability",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn find_available(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FindAvailableRequest>,
+        ) -> std::result::Result<tonic::Response<super::FindAvailableResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/find_available",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "find_available",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn reserve_any(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ReserveAnyRequest>,
+        ) -> std::result::Result<tonic::Response<super::ReserveAnyResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/reserve_any");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "reserve_any",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn subscribe(
             &mut self,
             request: impl tonic::IntoRequest<super::SubscribeRequest>,
         ) -> std::result::Result<
-            tonic::Response<tonic::codec::Streaming<super::Reservation>>,
+            tonic::Response<tonic::codec::Streaming<super::SubscribeResponse>>,
             tonic::Status,
         > {
             self.inner.ready().await.map_err(|e| {
@@ -388,6 +1013,101 @@ pub mod reservation_service_client {
             ));
             self.inner.server_streaming(req, path, codec).await
         }
+        pub async fn create_resource(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CreateResourceRequest>,
+        ) -> std::result::Result<tonic::Response<super::CreateResourceResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/create_resource",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "create_resource",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_resource(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetResourceRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetResourceResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/get_resource",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "get_resource",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn update_resource(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UpdateResourceRequest>,
+        ) -> std::result::Result<tonic::Response<super::UpdateResourceResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/update_resource",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "update_resource",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_resources(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListResourcesRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListResourcesResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/list_resources",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "list_resources",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn deactivate_resource(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeactivateResourceRequest>,
+        ) -> std::result::Result<tonic::Response<super::DeactivateResourceResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/deactivate_resource",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "deactivate_resource",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -407,6 +1127,18 @@ pub mod reservation_service_server {
             &self,
             request: tonic::Request<super::ReserveRequest>,
         ) -> std::result::Result<tonic::Response<super::ReserveResponse>, tonic::Status>;
+        async fn reserve_many(
+            &self,
+            request: tonic::Request<super::ReserveManyRequest>,
+        ) -> std::result::Result<tonic::Response<super::ReserveManyResponse>, tonic::Status>;
+        async fn reserve_recurring(
+            &self,
+            request: tonic::Request<super::ReserveRecurringRequest>,
+        ) -> std::result::Result<tonic::Response<super::ReserveRecurringResponse>, tonic::Status>;
+        async fn block(
+            &self,
+            request: tonic::Request<super::BlockRequest>,
+        ) -> std::result::Result<tonic::Response<super::BlockResponse>, tonic::Status>;
         async fn confirm(
             &self,
             request: tonic::Request<super::ConfirmRequest>,
@@ -415,14 +1147,26 @@ pub mod reservation_service_server {
             &self,
             request: tonic::Request<super::UpdateRequest>,
         ) -> std::result::Result<tonic::Response<super::UpdateResponse>, tonic::Status>;
+        async fn reschedule(
+            &self,
+            request: tonic::Request<super::RescheduleRequest>,
+        ) -> std::result::Result<tonic::Response<super::RescheduleResponse>, tonic::Status>;
+        async fn swap(
+            &self,
+            request: tonic::Request<super::SwapRequest>,
+        ) -> std::result::Result<tonic::Response<super::SwapResponse>, tonic::Status>;
+        async fn update_attributes(
+            &self,
+            request: tonic::Request<super::UpdateAttributesRequest>,
+        ) -> std::result::Result<tonic::Response<super::UpdateAttributesResponse>, tonic::Status>;
         async fn cancel(
             &self,
             request: tonic::Request<super::CancelRequest>,
         ) -> std::result::Result<tonic::Response<super::CancelResponse>, tonic::Status>;
         async fn get(
             &self,
-            request: tonic::Request<super::Reservation>,
-        ) -> std::result::Result<tonic::Response<super::Reservation>, tonic::Status>;
+            request: tonic::Request<super::GetRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetResponse>, tonic::Status>;
         /// Server streaming response type for the query method.
         type queryStream: tonic::codegen::tokio_stream::Stream<
                 Item = std::result::Result<super::Reservation, tonic::Status>,
@@ -432,15 +1176,51 @@ pub mod reservation_service_server {
             &self,
             request: tonic::Request<super::QueryRequest>,
         ) -> std::result::Result<tonic::Response<Self::queryStream>, tonic::Status>;
+        async fn query_page(
+            &self,
+            request: tonic::Request<super::QueryRequest>,
+        ) -> std::result::Result<tonic::Response<super::ReservationPage>, tonic::Status>;
+        async fn availability(
+            &self,
+            request: tonic::Request<super::AvailabilityRequest>,
+        ) -> std::result::Result<tonic::Response<super::AvailabilityResponse>, tonic::Status>;
+        async fn find_available(
+            &self,
+            request: tonic::Request<super::FindAvailableRequest>,
+        ) -> std::result::Result<tonic::Response<super::FindAvailableResponse>, tonic::Status>;
+        async fn reserve_any(
+            &self,
+            request: tonic::Request<super::ReserveAnyRequest>,
+        ) -> std::result::Result<tonic::Response<super::ReserveAnyResponse>, tonic::Status>;
         /// Server streaming response type for the subscribe method.
         type subscribeStream: tonic::codegen::tokio_stream::Stream<
-                Item = std::result::Result<super::Reservation, tonic::Status>,
+                Item = std::result::Result<super::SubscribeResponse, tonic::Status>,
             > + std::marker::Send
             + 'static;
         async fn subscribe(
             &self,
             request: tonic::Request<super::SubscribeRequest>,
         ) -> std::result::Result<tonic::Response<Self::subscribeStream>, tonic::Status>;
+        async fn create_resource(
+            &self,
+            request: tonic::Request<super::CreateResourceRequest>,
+        ) -> std::result::Result<tonic::Response<super::CreateResourceResponse>, tonic::Status>;
+        async fn get_resource(
+            &self,
+            request: tonic::Request<super::GetResourceRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetResourceResponse>, tonic::Status>;
+        async fn update_resource(
+            &self,
+            request: tonic::Request<super::UpdateResourceRequest>,
+        ) -> std::result::Result<tonic::Response<super::UpdateResourceResponse>, tonic::Status>;
+        async fn list_resources(
+            &self,
+            request: tonic::Request<super::ListResourcesRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListResourcesResponse>, tonic::Status>;
+        async fn deactivate_resource(
+            &self,
+            request: tonic::Request<super::DeactivateResourceRequest>,
+        ) -> std::result::Result<tonic::Response<super::DeactivateResourceResponse>, tonic::Status>;
     }
     #[derive(Debug)]
     pub struct ReservationServiceServer<T> {
@@ -554,19 +1334,22 @@ pub mod reservation_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/reservation.ReservationService/confirm" => {
+                "/reservation.ReservationService/reserve_many" => {
                     #[allow(non_camel_case_types)]
-                    struct confirmSvc<T: ReservationService>(pub Arc<T>);
-                    impl<T: ReservationService> tonic::server::UnaryService<super::ConfirmRequest> for confirmSvc<T> {
-                        type Response = super::ConfirmResponse;
+                    struct reserve_manySvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::ReserveManyRequest>
+                        for reserve_manySvc<T>
+                    {
+                        type Response = super::ReserveManyResponse;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::ConfirmRequest>,
+                            request: tonic::Request<super::ReserveManyRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ReservationService>::confirm(&inner, request).await
+                                <T as ReservationService>::reserve_many(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -577,7 +1360,7 @@ pub mod reservation_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = confirmSvc(inner);
+                        let method = reserve_manySvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -593,19 +1376,22 @@ pub mod reservation_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/reservation.ReservationService/update" => {
+                "/reservation.ReservationService/reserve_recurring" => {
                     #[allow(non_camel_case_types)]
-                    struct updateSvc<T: ReservationService>(pub Arc<T>);
-                    impl<T: ReservationService> tonic::server::UnaryService<super::UpdateRequest> for updateSvc<T> {
-                        type Response = super::UpdateResponse;
+                    struct reserve_recurringSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::ReserveRecurringRequest>
+                        for reserve_recurringSvc<T>
+                    {
+                        type Response = super::ReserveRecurringResponse;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::UpdateRequest>,
+                            request: tonic::Request<super::ReserveRecurringRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ReservationService>::update(&inner, request).await
+                                <T as ReservationService>::reserve_recurring(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -616,7 +1402,7 @@ pub mod reservation_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = updateSvc(inner);
+                        let method = reserve_recurringSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -632,19 +1418,19 @@ pub mod reservation_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/reservation.ReservationService/cancel" => {
+                "/reservation.ReservationService/block" => {
                     #[allow(non_camel_case_types)]
-                    struct cancelSvc<T: ReservationService>(pub Arc<T>);
-                    impl<T: ReservationService> tonic::server::UnaryService<super::CancelRequest> for cancelSvc<T> {
-                        type Response = super::CancelResponse;
+                    struct blockSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::BlockRequest> for blockSvc<T> {
+                        type Response = super::BlockResponse;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::CancelRequest>,
+                            request: tonic::Request<super::BlockRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ReservationService>::cancel(&inner, request).await
+                                <T as ReservationService>::block(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -655,7 +1441,7 @@ pub mod reservation_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = cancelSvc(inner);
+                        let method = blockSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -671,19 +1457,19 @@ pub mod reservation_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/reservation.ReservationService/get" => {
+                "/reservation.ReservationService/confirm" => {
                     #[allow(non_camel_case_types)]
-                    struct getSvc<T: ReservationService>(pub Arc<T>);
-                    impl<T: ReservationService> tonic::server::UnaryService<super::Reservation> for getSvc<T> {
-                        type Response = super::Reservation;
+                    struct confirmSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::ConfirmRequest> for confirmSvc<T> {
+                        type Response = super::ConfirmResponse;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::Reservation>,
+                            request: tonic::Request<super::ConfirmRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ReservationService>::get(&inner, request).await
+                                <T as ReservationService>::confirm(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -694,7 +1480,7 @@ pub mod reservation_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = getSvc(inner);
+                        let method = confirmSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -710,23 +1496,19 @@ pub mod reservation_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/reservation.ReservationService/query" => {
+                "/reservation.ReservationService/update" => {
                     #[allow(non_camel_case_types)]
-                    struct querySvc<T: ReservationService>(pub Arc<T>);
-                    impl<T: ReservationService>
-                        tonic::server::ServerStreamingService<super::QueryRequest> for querySvc<T>
-                    {
-                        type Response = super::Reservation;
-                        type ResponseStream = T::queryStream;
-                        type Future =
-                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                    struct updateSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::UpdateRequest> for updateSvc<T> {
+                        type Response = super::UpdateResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::QueryRequest>,
+                            request: tonic::Request<super::UpdateRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ReservationService>::query(&inner, request).await
+                                <T as ReservationService>::update(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -737,7 +1519,7 @@ pub mod reservation_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = querySvc(inner);
+                        let method = updateSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -748,29 +1530,26 @@ pub mod reservation_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.server_streaming(method, req).await;
+                        let res = grpc.unary(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/reservation.ReservationService/subscribe" => {
+                "/reservation.ReservationService/reschedule" => {
                     #[allow(non_camel_case_types)]
-                    struct subscribeSvc<T: ReservationService>(pub Arc<T>);
+                    struct rescheduleSvc<T: ReservationService>(pub Arc<T>);
                     impl<T: ReservationService>
-                        tonic::server::ServerStreamingService<super::SubscribeRequest>
-                        for subscribeSvc<T>
+                        tonic::server::UnaryService<super::RescheduleRequest> for rescheduleSvc<T>
                     {
-                        type Response = super::Reservation;
-                        type ResponseStream = T::subscribeStream;
-                        type Future =
-                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        type Response = super::RescheduleResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::SubscribeRequest>,
+                            request: tonic::Request<super::RescheduleRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ReservationService>::subscribe(&inner, request).await
+                                <T as ReservationService>::reschedule(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -781,7 +1560,7 @@ pub mod reservation_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = subscribeSvc(inner);
+                        let method = rescheduleSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -792,7 +1571,629 @@ pub mod reservation_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.server_streaming(method, req).await;
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/swap" => {
+                    #[allow(non_camel_case_types)]
+                    struct swapSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::SwapRequest> for swapSvc<T> {
+                        type Response = super::SwapResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SwapRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::swap(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = swapSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/update_attributes" => {
+                    #[allow(non_camel_case_types)]
+                    struct update_attributesSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::UpdateAttributesRequest>
+                        for update_attributesSvc<T>
+                    {
+                        type Response = super::UpdateAttributesResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UpdateAttributesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::update_attributes(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = update_attributesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/cancel" => {
+                    #[allow(non_camel_case_types)]
+                    struct cancelSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::CancelRequest> for cancelSvc<T> {
+                        type Response = super::CancelResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CancelRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::cancel(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = cancelSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/get" => {
+                    #[allow(non_camel_case_types)]
+                    struct getSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::GetRequest> for getSvc<T> {
+                        type Response = super::GetResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::get(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = getSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/query" => {
+                    #[allow(non_camel_case_types)]
+                    struct querySvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::ServerStreamingService<super::QueryRequest> for querySvc<T>
+                    {
+                        type Response = super::Reservation;
+                        type ResponseStream = T::queryStream;
+                        type Future =
+                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::query(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = querySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/query_page" => {
+                    #[allow(non_camel_case_types)]
+                    struct query_pageSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::QueryRequest> for query_pageSvc<T> {
+                        type Response = super::ReservationPage;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::query_page(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = query_pageSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/availability" => {
+                    #[allow(non_camel_case_types)]
+                    struct availabilitySvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::AvailabilityRequest>
+                        for availabilitySvc<T>
+                    {
+                        type Response = super::AvailabilityResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AvailabilityRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::availability(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = availabilitySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/find_available" => {
+                    #[allow(non_camel_case_types)]
+                    struct find_availableSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::FindAvailableRequest>
+                        for find_availableSvc<T>
+                    {
+                        type Response = super::FindAvailableResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FindAvailableRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::find_available(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = find_availableSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/reserve_any" => {
+                    #[allow(non_camel_case_types)]
+                    struct reserve_anySvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::ReserveAnyRequest>
+                        for reserve_anySvc<T>
+                    {
+                        type Response = super::ReserveAnyResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ReserveAnyRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::reserve_any(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = reserve_anySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/subscribe" => {
+                    #[allow(non_camel_case_types)]
+                    struct subscribeSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::ServerStreamingService<super::SubscribeRequest>
+                        for subscribeSvc<T>
+                    {
+                        type Response = super::SubscribeResponse;
+                        type ResponseStream = T::subscribeStream;
+                        type Future =
+                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SubscribeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::subscribe(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = subscribeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/create_resource" => {
+                    #[allow(non_camel_case_types)]
+                    struct create_resourceSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::CreateResourceRequest>
+                        for create_resourceSvc<T>
+                    {
+                        type Response = super::CreateResourceResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CreateResourceRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::create_resource(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = create_resourceSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/get_resource" => {
+                    #[allow(non_camel_case_types)]
+                    struct get_resourceSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::GetResourceRequest>
+                        for get_resourceSvc<T>
+                    {
+                        type Response = super::GetResourceResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetResourceRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::get_resource(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = get_resourceSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/update_resource" => {
+                    #[allow(non_camel_case_types)]
+                    struct update_resourceSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::UpdateResourceRequest>
+                        for update_resourceSvc<T>
+                    {
+                        type Response = super::UpdateResourceResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UpdateResourceRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::update_resource(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = update_resourceSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/list_resources" => {
+                    #[allow(non_camel_case_types)]
+                    struct list_resourcesSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::ListResourcesRequest>
+                        for list_resourcesSvc<T>
+                    {
+                        type Response = super::ListResourcesResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListResourcesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::list_resources(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = list_resourcesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/deactivate_resource" => {
+                    #[allow(non_camel_case_types)]
+                    struct deactivate_resourceSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::DeactivateResourceRequest>
+                        for deactivate_resourceSvc<T>
+                    {
+                        type Response = super::DeactivateResourceResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DeactivateResourceRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::deactivate_resource(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = deactivate_resourceSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
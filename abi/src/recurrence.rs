@@ -0,0 +1,355 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, TimeDelta, Utc, Weekday};
+
+use crate::Error;
+
+/// upper bound of occurrences a single rule can expand into
+pub const MAX_OCCURRENCES: usize = 366;
+/// upper bound of the `INTERVAL` of a rule
+pub const MAX_INTERVAL: u32 = 1000;
+
+/// start and end of an occurrence
+pub type Occurrence = (DateTime<Utc>, DateTime<Utc>);
+
+/// A subset of the RFC 5545 RRULE: `FREQ` (DAILY or WEEKLY), `INTERVAL`, `COUNT`, `UNTIL`
+/// and `BYDAY`. Either `COUNT` or `UNTIL` is required. Rules are evaluated in UTC.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecurrenceRule {
+    pub freq: Frequency,
+    pub interval: u32,
+    pub count: Option<usize>,
+    pub until: Option<DateTime<Utc>>,
+    pub by_day: Vec<Weekday>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+}
+
+impl RecurrenceRule {
+    /// expand the rule into occurrence windows, the first one being `start` to `end`
+    pub fn occurrences(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<Occurrence>, Error> {
+        let duration = end - start;
+        let limit = self.count.unwrap_or(MAX_OCCURRENCES + 1);
+        let mut occurrences = Vec::new();
+
+        for candidate in self.candidates(start) {
+            if occurrences.len() == limit || self.until.is_some_and(|until| candidate > until) {
+                break;
+            }
+            if occurrences.len() == MAX_OCCURRENCES {
+                return Err(invalid_rule(format!(
+                    "more than {MAX_OCCURRENCES} occurrences"
+                )));
+            }
+            let end = candidate
+                .checked_add_signed(duration)
+                .ok_or_else(|| invalid_rule("occurrence out of range"))?;
+            occurrences.push((candidate, end));
+        }
+
+        if occurrences.is_empty() {
+            return Err(invalid_rule("no occurrences"));
+        }
+        Ok(occurrences)
+    }
+
+    /// candidate occurrence starts in order, `start` being the first one even if the rule
+    /// doesn't match it, as DTSTART in RFC 5545
+    fn candidates(&self, start: DateTime<Utc>) -> impl Iterator<Item = DateTime<Utc>> + '_ {
+        let interval = self.interval as i64;
+        let (step, days) = match self.freq {
+            Frequency::Daily => (TimeDelta::days(interval), vec![TimeDelta::zero()]),
+            Frequency::Weekly => {
+                // a weekly rule without BYDAY repeats on the weekday of the first occurrence
+                let by_day = if self.by_day.is_empty() {
+                    vec![start.weekday()]
+                } else {
+                    self.by_day.clone()
+                };
+                let mut days: Vec<_> = by_day
+                    .iter()
+                    .map(|day| {
+                        TimeDelta::days(
+                            day.num_days_from_monday() as i64
+                                - start.weekday().num_days_from_monday() as i64,
+                        )
+                    })
+                    .collect();
+                days.sort();
+                days.dedup();
+                (TimeDelta::weeks(interval), days)
+            }
+        };
+
+        // a daily rule whose step never lands on a BYDAY weekday yields no more candidates,
+        // so the number of steps is bounded as well. Steps past the supported dates end it
+        let rest = (0..MAX_OCCURRENCES as i32 * 7)
+            .map_while(move |n| start.checked_add_signed(step.checked_mul(n)?))
+            .take_while(|base| {
+                self.until
+                    .is_none_or(|until| *base - TimeDelta::weeks(1) <= until)
+            })
+            .flat_map(move |base| {
+                days.clone()
+                    .into_iter()
+                    .filter_map(move |day| base.checked_add_signed(day))
+            })
+            .filter(move |candidate| {
+                *candidate > start
+                    && (self.freq == Frequency::Weekly
+                        || self.by_day.is_empty()
+                        || self.by_day.contains(&candidate.weekday()))
+            });
+        std::iter::once(start).chain(rest)
+    }
+}
+
+impl FromStr for RecurrenceRule {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let s = s.strip_prefix("RRULE:").unwrap_or(s);
+
+        let mut freq = None;
+        let mut interval = 1;
+        let mut count = None;
+        let mut until = None;
+        let mut by_day = Vec::new();
+
+        for part in s.split(';').filter(|part| !part.is_empty()) {
+            let (key, value) = part
+                .split_once('=')
+                .ok_or_else(|| invalid_rule(format!("malformed part {part}")))?;
+            match key {
+                "FREQ" => {
+                    freq = Some(match value {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        _ => return Err(invalid_rule(format!("unsupported FREQ {value}"))),
+                    })
+                }
+                "INTERVAL" => {
+                    interval = parse_number(key, value)?;
+                    if interval > MAX_INTERVAL {
+                        return Err(invalid_rule(format!(
+                            "INTERVAL is larger than {MAX_INTERVAL}"
+                        )));
+                    }
+                }
+                "COUNT" => count = Some(parse_number(key, value)? as usize),
+                "UNTIL" => until = Some(parse_until(value)?),
+                "BYDAY" => {
+                    by_day = value
+                        .split(',')
+                        .map(parse_weekday)
+                        .collect::<Result<_, _>>()?
+                }
+                _ => return Err(invalid_rule(format!("unsupported part {key}"))),
+            }
+        }
+
+        let freq = freq.ok_or_else(|| invalid_rule("missing FREQ"))?;
+        if count.is_none() && until.is_none() {
+            return Err(invalid_rule("either COUNT or UNTIL is required"));
+        }
+        if count.is_some_and(|count| count > MAX_OCCURRENCES) {
+            return Err(invalid_rule(format!(
+                "COUNT is larger than {MAX_OCCURRENCES}"
+            )));
+        }
+
+        Ok(Self {
+            freq,
+            interval,
+            count,
+            until,
+            by_day,
+        })
+    }
+}
+
+fn invalid_rule(reason: impl Into<String>) -> Error {
+    Error::InvalidRecurrenceRule(reason.into())
+}
+
+fn parse_number(key: &str, value: &str) -> Result<u32, Error> {
+    match value.parse() {
+        Ok(n) if n > 0 => Ok(n),
+        _ => Err(invalid_rule(format!("invalid {key} {value}"))),
+    }
+}
+
+/// UNTIL is either a UTC date-time (`20250801T100000Z`) or a date (`20250801`)
+fn parse_until(value: &str) -> Result<DateTime<Utc>, Error> {
+    if let Ok(dt) = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%SZ") {
+        return Ok(dt.and_utc());
+    }
+    NaiveDate::parse_from_str(value, "%Y%m%d")
+        .map(|date| date.and_hms_opt(23, 59, 59).unwrap().and_utc())
+        .map_err(|_| invalid_rule(format!("invalid UNTIL {value}")))
+}
+
+fn parse_weekday(value: &str) -> Result<Weekday, Error> {
+    match value {
+        "MO" => Ok(Weekday::Mon),
+        "TU" => Ok(Weekday::Tue),
+        "WE" => Ok(Weekday::Wed),
+        "TH" => Ok(Weekday::Thu),
+        "FR" => Ok(Weekday::Fri),
+        "SA" => Ok(Weekday::Sat),
+        "SU" => Ok(Weekday::Sun),
+        _ => Err(invalid_rule(format!("invalid BYDAY {value}"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn rule_should_parse() {
+        let rule: RecurrenceRule = "RRULE:FREQ=WEEKLY;INTERVAL=2;COUNT=12;BYDAY=TU,TH"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            rule,
+            RecurrenceRule {
+                freq: Frequency::Weekly,
+                interval: 2,
+                count: Some(12),
+                until: None,
+                by_day: vec![Weekday::Tue, Weekday::Thu],
+            }
+        );
+    }
+
+    #[test]
+    fn unbounded_or_unsupported_rule_should_be_rejected() {
+        assert!("FREQ=WEEKLY".parse::<RecurrenceRule>().is_err());
+        assert!("FREQ=MONTHLY;COUNT=3".parse::<RecurrenceRule>().is_err());
+        assert!("FREQ=DAILY;COUNT=0".parse::<RecurrenceRule>().is_err());
+        assert!(
+            "FREQ=DAILY;COUNT=3;BYDAY=XX"
+                .parse::<RecurrenceRule>()
+                .is_err()
+        );
+        assert!(
+            "FREQ=DAILY;UNTIL=tomorrow"
+                .parse::<RecurrenceRule>()
+                .is_err()
+        );
+        assert_eq!(
+            "FREQ=WEEKLY;INTERVAL=4294967295;COUNT=3"
+                .parse::<RecurrenceRule>()
+                .unwrap_err(),
+            Error::InvalidRecurrenceRule(format!("INTERVAL is larger than {MAX_INTERVAL}"))
+        );
+    }
+
+    #[test]
+    fn rule_stepping_past_supported_dates_should_not_panic() {
+        let rule = RecurrenceRule {
+            freq: Frequency::Weekly,
+            interval: u32::MAX,
+            count: Some(3),
+            until: None,
+            by_day: vec![],
+        };
+        let occurrences = rule
+            .occurrences(utc("2025-06-04T08:00:00Z"), utc("2025-06-04T09:00:00Z"))
+            .unwrap();
+        assert_eq!(occurrences.len(), 1);
+    }
+
+    #[test]
+    fn weekly_rule_should_repeat_on_start_weekday() {
+        let rule: RecurrenceRule = "FREQ=WEEKLY;COUNT=12".parse().unwrap();
+        // 2025-06-03 is a Tuesday
+        let occurrences = rule
+            .occurrences(utc("2025-06-03T10:00:00Z"), utc("2025-06-03T10:30:00Z"))
+            .unwrap();
+
+        assert_eq!(occurrences.len(), 12);
+        assert_eq!(occurrences[1].0, utc("2025-06-10T10:00:00Z"));
+        assert_eq!(occurrences[11].0, utc("2025-08-19T10:00:00Z"));
+        assert_eq!(occurrences[11].1, utc("2025-08-19T10:30:00Z"));
+    }
+
+    #[test]
+    fn daily_rule_with_byday_should_skip_weekends() {
+        let rule: RecurrenceRule = "FREQ=DAILY;BYDAY=MO,TU,WE,TH,FR;UNTIL=20250613"
+            .parse()
+            .unwrap();
+        // 2025-06-04 is a Wednesday
+        let occurrences = rule
+            .occurrences(utc("2025-06-04T08:00:00Z"), utc("2025-06-04T18:00:00Z"))
+            .unwrap();
+
+        let starts: Vec<_> = occurrences.iter().map(|(start, _)| start.day()).collect();
+        assert_eq!(starts, vec![4, 5, 6, 9, 10, 11, 12, 13]);
+    }
+
+    #[test]
+    fn weekly_rule_with_byday_should_not_start_before_first_occurrence() {
+        let rule: RecurrenceRule = "FREQ=WEEKLY;BYDAY=MO,WE;COUNT=3".parse().unwrap();
+        // starts on a Wednesday, so the Monday of that week is skipped
+        let occurrences = rule
+            .occurrences(utc("2025-06-04T08:00:00Z"), utc("2025-06-04T09:00:00Z"))
+            .unwrap();
+
+        let starts: Vec<_> = occurrences.iter().map(|(start, _)| start.day()).collect();
+        assert_eq!(starts, vec![4, 9, 11]);
+    }
+
+    #[test]
+    fn rule_expanding_to_too_many_occurrences_should_be_rejected() {
+        let rule: RecurrenceRule = "FREQ=DAILY;UNTIL=20300101".parse().unwrap();
+        let ret = rule.occurrences(utc("2025-06-04T08:00:00Z"), utc("2025-06-04T09:00:00Z"));
+        assert!(ret.is_err());
+    }
+
+    #[test]
+    fn start_should_be_the_first_occurrence_even_if_unmatched() {
+        // 2025-06-04 is a Wednesday
+        let rule: RecurrenceRule = "FREQ=WEEKLY;BYDAY=MO;COUNT=3".parse().unwrap();
+        let occurrences = rule
+            .occurrences(utc("2025-06-04T08:00:00Z"), utc("2025-06-04T09:00:00Z"))
+            .unwrap();
+        let starts: Vec<_> = occurrences.iter().map(|(start, _)| start.day()).collect();
+        assert_eq!(starts, vec![4, 9, 16]);
+
+        // every 7 days from a Wednesday never lands on a Monday again
+        let rule: RecurrenceRule = "FREQ=DAILY;INTERVAL=7;BYDAY=MO;COUNT=3".parse().unwrap();
+        let occurrences = rule
+            .occurrences(utc("2025-06-04T08:00:00Z"), utc("2025-06-04T09:00:00Z"))
+            .unwrap();
+        assert_eq!(
+            occurrences,
+            vec![(utc("2025-06-04T08:00:00Z"), utc("2025-06-04T09:00:00Z"))]
+        );
+    }
+
+    #[test]
+    fn rule_without_occurrences_should_be_rejected() {
+        // the rule ends before the first occurrence
+        let rule: RecurrenceRule = "FREQ=DAILY;UNTIL=20250601".parse().unwrap();
+        let ret = rule.occurrences(utc("2025-06-04T08:00:00Z"), utc("2025-06-04T09:00:00Z"));
+        assert_eq!(
+            ret.unwrap_err(),
+            Error::InvalidRecurrenceRule("no occurrences".to_string())
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use chrono::{DateTime, TimeDelta, Utc};
+use sqlx::{
+    FromRow, Row,
+    postgres::{PgRow, types::PgRange},
+};
+
+use crate::{
+    AvailabilityQuery, Error, FreeInterval, Validator, convert_to_time_delta, convert_to_timestamp,
+    types::{get_timespan, validate_range},
+};
+
+impl AvailabilityQuery {
+    pub fn get_timespan(&self) -> PgRange<DateTime<Utc>> {
+        get_timespan(self.start.as_ref(), self.end.as_ref())
+    }
+
+    /// shortest free interval to return, zero if not set
+    pub fn get_min_duration(&self) -> Result<TimeDelta, Error> {
+        let Some(min_duration) = self.min_duration else {
+            return Ok(TimeDelta::zero());
+        };
+        // the database keeps intervals in microseconds
+        convert_to_time_delta(min_duration)
+            .filter(|d| *d >= TimeDelta::zero() && d.num_microseconds().is_some())
+            .ok_or(Error::InvalidMinDuration)
+    }
+}
+
+impl Validator for AvailabilityQuery {
+    fn validate(&self) -> Result<(), Error> {
+        if self.resource_ids.is_empty() {
+            return Err(Error::InvalidResourceId("".to_string()));
+        }
+        if let Some(id) = self.resource_ids.iter().find(|id| id.is_empty()) {
+            return Err(Error::InvalidResourceId(id.clone()));
+        }
+        validate_range(self.start.as_ref(), self.end.as_ref())?;
+        self.get_min_duration()?;
+        Ok(())
+    }
+}
+
+impl FromRow<'_, PgRow> for FreeInterval {
+    fn from_row(row: &PgRow) -> Result<Self, sqlx::Error> {
+        let start: DateTime<Utc> = row.try_get("start")?;
+        let end: DateTime<Utc> = row.try_get("end")?;
+        Ok(Self {
+            resource_id: row.try_get("resource_id")?,
+            start: Some(convert_to_timestamp(start)),
+            end: Some(convert_to_timestamp(end)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prost_types::{Duration, Timestamp};
+
+    use super::*;
+    use crate::AvailabilityQueryBuilder;
+
+    #[test]
+    fn availability_query_should_be_validated() {
+        let builder = || {
+            let mut builder = AvailabilityQueryBuilder::default();
+            builder
+                .start("2025-05-13T00:00:00Z".parse::<Timestamp>().unwrap())
+                .end("2025-05-14T00:00:00Z".parse::<Timestamp>().unwrap());
+            builder
+        };
+
+        let query = builder().build().unwrap();
+        assert_eq!(
+            query.validate().unwrap_err(),
+            Error::InvalidResourceId("".to_string())
+        );
+
+        let query = builder()
+            .resource_ids(vec!["ocean-view-room-777".to_string()])
+            .min_duration(Duration {
+                seconds: -60,
+                nanos: 0,
+            })
+            .build()
+            .unwrap();
+        assert_eq!(query.validate().unwrap_err(), Error::InvalidMinDuration);
+
+        let query = builder()
+            .resource_ids(vec!["ocean-view-room-777".to_string()])
+            .min_duration(Duration {
+                seconds: i64::MAX,
+                nanos: 0,
+            })
+            .build()
+            .unwrap();
+        assert_eq!(query.validate().unwrap_err(), Error::InvalidMinDuration);
+
+        let query = builder()
+            .resource_ids(vec!["ocean-view-room-777".to_string()])
+            .build()
+            .unwrap();
+        assert!(query.validate().is_ok());
+        assert_eq!(query.get_min_duration().unwrap(), TimeDelta::zero());
+    }
+}
@@ -5,9 +5,16 @@ use chrono::{DateTime, Utc};
 use prost_types::Timestamp;
 use sqlx::postgres::types::PgRange;
 
+mod availability;
 mod reservation;
+mod reservation_change;
 mod reservation_query;
 mod reservation_status;
+mod reservation_update_type;
+mod resource;
+mod resource_group;
+
+pub use reservation_query::ReservationCursor;
 
 pub fn validate_range(start: Option<&Timestamp>, end: Option<&Timestamp>) -> Result<(), Error> {
     if start.is_none() || end.is_none() {
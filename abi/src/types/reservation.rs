@@ -1,13 +1,16 @@
-use std::ops::Bound;
+use std::{collections::HashMap, ops::Bound};
 
-use chrono::{DateTime, FixedOffset, Utc};
+use chrono::{DateTime, FixedOffset, TimeDelta, Utc};
+use prost_types::Duration;
 use sqlx::{
     FromRow, Row,
     postgres::{PgRow, types::PgRange},
+    types::Json,
 };
 
 use crate::{
-    Error, Reservation, ReservationStatus, RsvpStatus, Validator, convert_to_timestamp,
+    Error, RecurrenceRule, Reservation, ReservationStatus, RsvpStatus, Validator,
+    convert_to_time_delta, convert_to_timestamp, convert_to_utc_time,
     types::{get_timespan, validate_range},
 };
 
@@ -27,9 +30,85 @@ impl Reservation {
             end: Some(convert_to_timestamp(end.with_timezone(&Utc))),
             status: ReservationStatus::Pending as i32,
             note: note.into(),
+            expires_at: None,
+            series_id: 0,
+            attributes: HashMap::new(),
+            version: 0,
+            cancelled_by: String::new(),
+            cancelled_at: None,
         }
     }
 
+    pub fn with_attribute(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.attributes.insert(key.into(), value.into());
+        self
+    }
+
+    /// hold the reservation until `expires_at`, it is cancelled if still pending by then
+    pub fn with_expiration(mut self, expires_at: DateTime<Utc>) -> Self {
+        self.expires_at = Some(convert_to_timestamp(expires_at));
+        self
+    }
+
+    /// hold the reservation for `ttl` from now, if given
+    pub fn with_hold_ttl(self, ttl: Option<Duration>) -> Result<Self, Error> {
+        let Some(ttl) = ttl else {
+            return Ok(self);
+        };
+        let expires_at = convert_to_time_delta(ttl)
+            .filter(|ttl| *ttl > TimeDelta::zero())
+            .and_then(|ttl| Utc::now().checked_add_signed(ttl))
+            .ok_or(Error::InvalidHoldTtl)?;
+        Ok(self.with_expiration(expires_at))
+    }
+
+    /// expand the reservation into the occurrences of `rule`, itself being the first one
+    pub fn expand(&self, rule: &RecurrenceRule) -> Result<Vec<Self>, Error> {
+        self.validate()?;
+        let (start, end) = self.get_window();
+
+        let occurrences = rule
+            .occurrences(start, end)?
+            .into_iter()
+            .map(|(start, end)| Self {
+                start: Some(convert_to_timestamp(start)),
+                end: Some(convert_to_timestamp(end)),
+                ..self.clone()
+            })
+            .collect();
+        Ok(occurrences)
+    }
+
+    /// status the reservation is created with: pending unless it is confirmed right away.
+    /// Other statuses are only reached through transitions
+    pub fn get_initial_status(&self) -> Result<ReservationStatus, Error> {
+        match ReservationStatus::try_from(self.status) {
+            Ok(ReservationStatus::Unknown) => Ok(ReservationStatus::Pending),
+            Ok(status @ (ReservationStatus::Pending | ReservationStatus::Confirmed)) => Ok(status),
+            Ok(requested) => Err(Error::InvalidTransition {
+                current: ReservationStatus::Unknown,
+                requested,
+            }),
+            Err(_) => Err(Error::InvalidStatus(self.status)),
+        }
+    }
+
+    pub fn get_expires_at(&self) -> Option<DateTime<Utc>> {
+        self.expires_at.map(convert_to_utc_time)
+    }
+
+    pub fn get_cancelled_at(&self) -> Option<DateTime<Utc>> {
+        self.cancelled_at.map(convert_to_utc_time)
+    }
+
+    /// start and end of the reservation window
+    pub fn get_window(&self) -> (DateTime<Utc>, DateTime<Utc>) {
+        (
+            convert_to_utc_time(self.start.unwrap()),
+            convert_to_utc_time(self.end.unwrap()),
+        )
+    }
+
     pub fn get_timespan(&self) -> PgRange<DateTime<Utc>> {
         get_timespan(self.start.as_ref(), self.end.as_ref())
     }
@@ -47,6 +126,13 @@ impl Validator for Reservation {
             return Err(Error::InvalidTime);
         }
         validate_range(self.start.as_ref(), self.end.as_ref())?;
+        // an out of range expiration would otherwise be read as now
+        if let Some(expires_at) = self.expires_at
+            && (expires_at.nanos < 0
+                || DateTime::from_timestamp(expires_at.seconds, expires_at.nanos as u32).is_none())
+        {
+            return Err(Error::InvalidHoldTtl);
+        }
         Ok(())
     }
 }
@@ -70,6 +156,13 @@ impl FromRow<'_, PgRow> for Reservation {
         let end = range.end.unwrap();
 
         let status: RsvpStatus = row.get("status");
+        let expires_at: Option<DateTime<Utc>> = row.get("expires_at");
+        let series_id: Option<i64> = row.get("series_id");
+        // change snapshots recorded before attributes and versions existed have none
+        let attributes: Option<Json<HashMap<String, String>>> = row.try_get("attributes")?;
+        let version: Option<i64> = row.try_get("version")?;
+        let cancelled_by: Option<String> = row.try_get("cancelled_by")?;
+        let cancelled_at: Option<DateTime<Utc>> = row.try_get("cancelled_at")?;
 
         Ok(Self {
             id,
@@ -79,6 +172,12 @@ impl FromRow<'_, PgRow> for Reservation {
             end: Some(convert_to_timestamp(end)),
             note: row.get("note"),
             status: ReservationStatus::from(status) as i32,
+            expires_at: expires_at.map(convert_to_timestamp),
+            series_id: series_id.unwrap_or_default(),
+            attributes: attributes.map(|v| v.0).unwrap_or_default(),
+            version: version.unwrap_or_default(),
+            cancelled_by: cancelled_by.unwrap_or_default(),
+            cancelled_at: cancelled_at.map(convert_to_timestamp),
         })
     }
 }
@@ -100,3 +199,48 @@ impl<T> From<PgRange<T>> for NaiveRange<T> {
         Self { start, end }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use prost_types::Timestamp;
+
+    use super::*;
+
+    fn shur_reservation() -> Reservation {
+        Reservation::new_pending(
+            "shurid",
+            "ocean-view-room-777",
+            "2025-05-13T15:00:00-0700".parse().unwrap(),
+            "2025-05-15T12:00:00-0700".parse().unwrap(),
+            "this is shur's reservation",
+        )
+    }
+
+    #[test]
+    fn out_of_range_hold_ttl_should_be_rejected() {
+        let ttl = |seconds| Some(Duration { seconds, nanos: 0 });
+        assert!(shur_reservation().with_hold_ttl(ttl(900)).is_ok());
+        for seconds in [0, i64::MAX, i64::MAX / 1000 - 1] {
+            assert_eq!(
+                shur_reservation().with_hold_ttl(ttl(seconds)).unwrap_err(),
+                Error::InvalidHoldTtl
+            );
+        }
+    }
+
+    #[test]
+    fn out_of_range_expiration_should_be_rejected() {
+        let mut rsvp = shur_reservation();
+        rsvp.expires_at = Some(Timestamp {
+            seconds: i64::MAX,
+            nanos: 0,
+        });
+        assert_eq!(rsvp.validate().unwrap_err(), Error::InvalidHoldTtl);
+
+        rsvp.expires_at = Some(Timestamp {
+            seconds: 0,
+            nanos: -1,
+        });
+        assert_eq!(rsvp.validate().unwrap_err(), Error::InvalidHoldTtl);
+    }
+}
@@ -0,0 +1,26 @@
+use sqlx::{FromRow, Row, postgres::PgRow};
+
+use crate::{Reservation, ReservationUpdateType, RsvpUpdateType, SubscribeResponse};
+
+/// Build a change event from a `rsvp.reservation_changes` row expanded with its reservation
+/// snapshot. Changes recorded without a snapshot only have the reservation id populated.
+impl FromRow<'_, PgRow> for SubscribeResponse {
+    fn from_row(row: &PgRow) -> Result<Self, sqlx::Error> {
+        let op: RsvpUpdateType = row.try_get("op")?;
+        let user_id: Option<String> = row.try_get("user_id")?;
+
+        let reservation = match user_id {
+            Some(_) => Reservation::from_row(row)?,
+            None => Reservation {
+                id: row.try_get("reservation_id")?,
+                ..Default::default()
+            },
+        };
+
+        Ok(Self {
+            op: ReservationUpdateType::from(op) as i32,
+            reservation: Some(reservation),
+            change_id: row.try_get("change_id")?,
+        })
+    }
+}
@@ -1,20 +1,244 @@
+use std::{fmt, str::FromStr};
+
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
 use chrono::{DateTime, Utc};
 use sqlx::postgres::types::PgRange;
 
 use crate::{
-    Error, ReservationQuery, Validator,
+    Error, Reservation, ReservationQuery, ReservationStatus, Validator, convert_to_utc_time,
     types::{get_timespan, validate_range},
 };
 
+/// page size used when none is requested
+const DEFAULT_PAGE_SIZE: i32 = 10;
+const MAX_PAGE_SIZE: i32 = 100;
+
+/// Position after the last reservation of a page. Clients only see it as an opaque token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReservationCursor {
+    pub start: DateTime<Utc>,
+    pub id: i64,
+}
+
 impl ReservationQuery {
     pub fn get_timespan(&self) -> PgRange<DateTime<Utc>> {
         get_timespan(self.start.as_ref(), self.end.as_ref())
     }
+
+    /// requested page, the first one if unset
+    pub fn get_page(&self) -> i32 {
+        self.page.max(1)
+    }
+
+    /// requested page size, the default one if unset
+    pub fn get_page_size(&self) -> i32 {
+        if self.page_size == 0 {
+            DEFAULT_PAGE_SIZE
+        } else {
+            self.page_size
+        }
+    }
+
+    /// statuses to filter by, empty if reservations of any status match. Unknown values are
+    /// rejected by `validate`
+    pub fn get_statuses(&self) -> Vec<ReservationStatus> {
+        self.requested_statuses()
+            .iter()
+            .filter_map(|status| ReservationStatus::try_from(*status).ok())
+            .filter(|status| *status != ReservationStatus::Unknown)
+            .collect()
+    }
+
+    /// resources to filter by, empty if any resource matches
+    pub fn get_resource_ids(&self) -> Vec<String> {
+        merge_ids(&self.resource_id, &self.resource_ids)
+    }
+
+    /// users to filter by, empty if any user matches
+    pub fn get_user_ids(&self) -> Vec<String> {
+        merge_ids(&self.user_id, &self.user_ids)
+    }
+
+    fn requested_statuses(&self) -> &[i32] {
+        if self.statuses.is_empty() {
+            std::slice::from_ref(&self.status)
+        } else {
+            &self.statuses
+        }
+    }
+
+    pub fn get_cursor(&self) -> Result<Option<ReservationCursor>, Error> {
+        if self.cursor.is_empty() {
+            return Ok(None);
+        }
+        self.cursor.parse().map(Some)
+    }
+}
+
+fn merge_ids(id: &str, ids: &[String]) -> Vec<String> {
+    let mut merged: Vec<_> = std::iter::once(id)
+        .chain(ids.iter().map(String::as_str))
+        .filter(|id| !id.is_empty())
+        .map(String::from)
+        .collect();
+    merged.sort();
+    merged.dedup();
+    merged
 }
 
 impl Validator for ReservationQuery {
     fn validate(&self) -> Result<(), Error> {
         validate_range(self.start.as_ref(), self.end.as_ref())?;
+        if self.page < 0 {
+            return Err(Error::InvalidPage(self.page));
+        }
+        if !(0..=MAX_PAGE_SIZE).contains(&self.page_size) {
+            return Err(Error::InvalidPageSize(self.page_size));
+        }
+        if let Some(status) = self
+            .requested_statuses()
+            .iter()
+            .find(|status| ReservationStatus::try_from(**status).is_err())
+        {
+            return Err(Error::InvalidStatus(*status));
+        }
+        self.get_cursor()?;
         Ok(())
     }
 }
+
+impl From<&Reservation> for ReservationCursor {
+    fn from(rsvp: &Reservation) -> Self {
+        Self {
+            start: convert_to_utc_time(rsvp.start.unwrap()),
+            id: rsvp.id,
+        }
+    }
+}
+
+impl fmt::Display for ReservationCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let raw = format!("{}:{}", self.start.timestamp_micros(), self.id);
+        write!(f, "{}", URL_SAFE_NO_PAD.encode(raw))
+    }
+}
+
+impl FromStr for ReservationCursor {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidCursor(s.to_string());
+        let raw = URL_SAFE_NO_PAD.decode(s).map_err(|_| invalid())?;
+        let raw = String::from_utf8(raw).map_err(|_| invalid())?;
+        let (start, id) = raw.split_once(':').ok_or_else(invalid)?;
+        let start = start.parse().map_err(|_| invalid())?;
+        Ok(Self {
+            start: DateTime::from_timestamp_micros(start).ok_or_else(invalid)?,
+            id: id.parse().map_err(|_| invalid())?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_should_round_trip() {
+        let cursor = ReservationCursor {
+            start: "2025-05-13T22:00:00.000123Z".parse().unwrap(),
+            id: 42,
+        };
+        let token = cursor.to_string();
+        assert_eq!(token.parse::<ReservationCursor>().unwrap(), cursor);
+    }
+
+    #[test]
+    fn unknown_status_should_match_any_status() {
+        let query = ReservationQuery::default();
+        assert!(query.get_statuses().is_empty());
+
+        let query = ReservationQuery {
+            status: ReservationStatus::Blocked as i32,
+            statuses: vec![
+                ReservationStatus::Pending as i32,
+                ReservationStatus::Confirmed as i32,
+            ],
+            ..Default::default()
+        };
+        assert_eq!(
+            query.get_statuses(),
+            vec![ReservationStatus::Pending, ReservationStatus::Confirmed]
+        );
+    }
+
+    #[test]
+    fn single_and_repeated_ids_should_merge() {
+        let query = ReservationQuery {
+            resource_id: "room-2".to_string(),
+            resource_ids: vec!["room-1".to_string(), "room-2".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(query.get_resource_ids(), vec!["room-1", "room-2"]);
+        assert!(query.get_user_ids().is_empty());
+    }
+
+    #[test]
+    fn invalid_paging_should_be_rejected() {
+        let query = ReservationQuery {
+            start: Some(prost_types::Timestamp::default()),
+            end: Some(prost_types::Timestamp {
+                seconds: 3600,
+                nanos: 0,
+            }),
+            ..Default::default()
+        };
+        assert!(query.validate().is_ok());
+        assert_eq!(
+            (query.get_page(), query.get_page_size()),
+            (1, DEFAULT_PAGE_SIZE)
+        );
+
+        let oversized = ReservationQuery {
+            page_size: 101,
+            ..query.clone()
+        };
+        assert_eq!(
+            oversized.validate().unwrap_err(),
+            Error::InvalidPageSize(101)
+        );
+        let negative = ReservationQuery { page: -1, ..query };
+        assert_eq!(negative.validate().unwrap_err(), Error::InvalidPage(-1));
+    }
+
+    #[test]
+    fn unknown_status_should_be_rejected() {
+        let query = ReservationQuery {
+            start: Some(prost_types::Timestamp::default()),
+            end: Some(prost_types::Timestamp {
+                seconds: 3600,
+                nanos: 0,
+            }),
+            statuses: vec![ReservationStatus::Pending as i32, 99],
+            ..Default::default()
+        };
+        assert_eq!(query.validate().unwrap_err(), Error::InvalidStatus(99));
+
+        let query = ReservationQuery {
+            statuses: vec![],
+            status: -1,
+            ..query
+        };
+        assert_eq!(query.validate().unwrap_err(), Error::InvalidStatus(-1));
+    }
+
+    #[test]
+    fn malformed_cursor_should_be_rejected() {
+        assert_eq!(
+            "not a cursor".parse::<ReservationCursor>().unwrap_err(),
+            Error::InvalidCursor("not a cursor".to_string())
+        );
+        let token = URL_SAFE_NO_PAD.encode("42");
+        assert!(token.parse::<ReservationCursor>().is_err());
+    }
+}
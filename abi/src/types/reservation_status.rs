@@ -2,6 +2,20 @@ use std::fmt;
 
 use crate::{ReservationStatus, RsvpStatus};
 
+/// status changes allowed for a reservation, as (from, to)
+const TRANSITIONS: &[(ReservationStatus, ReservationStatus)] = &[
+    (ReservationStatus::Pending, ReservationStatus::Confirmed),
+    (ReservationStatus::Pending, ReservationStatus::Cancelled),
+    (ReservationStatus::Confirmed, ReservationStatus::Cancelled),
+    (ReservationStatus::Blocked, ReservationStatus::Cancelled),
+];
+
+impl ReservationStatus {
+    pub fn can_transition_to(&self, to: ReservationStatus) -> bool {
+        TRANSITIONS.contains(&(*self, to))
+    }
+}
+
 impl fmt::Display for ReservationStatus {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -9,6 +23,7 @@ impl fmt::Display for ReservationStatus {
             ReservationStatus::Pending => write!(f, "pending"),
             ReservationStatus::Confirmed => write!(f, "confirmed"),
             ReservationStatus::Blocked => write!(f, "blocked"),
+            ReservationStatus::Cancelled => write!(f, "cancelled"),
         }
     }
 }
@@ -20,6 +35,29 @@ impl From<RsvpStatus> for ReservationStatus {
             RsvpStatus::Pending => ReservationStatus::Pending,
             RsvpStatus::Confirmed => ReservationStatus::Confirmed,
             RsvpStatus::Blocked => ReservationStatus::Blocked,
+            RsvpStatus::Cancelled => ReservationStatus::Cancelled,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn declared_transitions_should_be_allowed() {
+        assert!(ReservationStatus::Pending.can_transition_to(ReservationStatus::Confirmed));
+        assert!(ReservationStatus::Pending.can_transition_to(ReservationStatus::Cancelled));
+        assert!(ReservationStatus::Confirmed.can_transition_to(ReservationStatus::Cancelled));
+        assert!(ReservationStatus::Blocked.can_transition_to(ReservationStatus::Cancelled));
+    }
+
+    #[test]
+    fn undeclared_transitions_should_be_rejected() {
+        assert!(!ReservationStatus::Confirmed.can_transition_to(ReservationStatus::Confirmed));
+        assert!(!ReservationStatus::Confirmed.can_transition_to(ReservationStatus::Pending));
+        assert!(!ReservationStatus::Cancelled.can_transition_to(ReservationStatus::Pending));
+        assert!(!ReservationStatus::Pending.can_transition_to(ReservationStatus::Blocked));
+        assert!(!ReservationStatus::Unknown.can_transition_to(ReservationStatus::Confirmed));
+    }
+}
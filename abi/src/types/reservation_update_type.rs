@@ -0,0 +1,25 @@
+use std::fmt;
+
+use crate::{ReservationUpdateType, RsvpUpdateType};
+
+impl fmt::Display for ReservationUpdateType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReservationUpdateType::Unknown => write!(f, "unknown"),
+            ReservationUpdateType::Create => write!(f, "create"),
+            ReservationUpdateType::Update => write!(f, "update"),
+            ReservationUpdateType::Delete => write!(f, "delete"),
+        }
+    }
+}
+
+impl From<RsvpUpdateType> for ReservationUpdateType {
+    fn from(op: RsvpUpdateType) -> Self {
+        match op {
+            RsvpUpdateType::Unknown => ReservationUpdateType::Unknown,
+            RsvpUpdateType::Create => ReservationUpdateType::Create,
+            RsvpUpdateType::Update => ReservationUpdateType::Update,
+            RsvpUpdateType::Delete => ReservationUpdateType::Delete,
+        }
+    }
+}
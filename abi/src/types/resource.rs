@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+use sqlx::{FromRow, Row, postgres::PgRow, types::Json};
+
+use crate::{Error, Resource, Validator};
+
+/// longest resource id accepted by the database
+const MAX_RESOURCE_ID_LEN: usize = 64;
+
+impl Resource {
+    pub fn new(id: impl Into<String>, name: impl Into<String>, kind: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            kind: kind.into(),
+            attributes: HashMap::new(),
+            active: true,
+            capacity: 1,
+        }
+    }
+
+    /// admit up to `capacity` reservations at the same time
+    pub fn with_capacity(mut self, capacity: i32) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    /// capacity stored in the database, an unset capacity means a single reservation
+    pub fn get_capacity(&self) -> i32 {
+        self.capacity.max(1)
+    }
+}
+
+impl Validator for Resource {
+    fn validate(&self) -> Result<(), Error> {
+        if self.id.is_empty() || self.id.len() > MAX_RESOURCE_ID_LEN {
+            return Err(Error::InvalidResourceId(self.id.clone()));
+        }
+        if self.capacity < 0 {
+            return Err(Error::InvalidCapacity(self.capacity));
+        }
+        Ok(())
+    }
+}
+
+impl FromRow<'_, PgRow> for Resource {
+    fn from_row(row: &PgRow) -> Result<Self, sqlx::Error> {
+        let attributes: Json<HashMap<String, String>> = row.try_get("attributes")?;
+        Ok(Self {
+            id: row.try_get("id")?,
+            name: row.try_get("name")?,
+            kind: row.try_get("kind")?,
+            attributes: attributes.0,
+            active: row.try_get("active")?,
+            capacity: row.try_get("capacity")?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resource_id_should_be_validated() {
+        assert!(
+            Resource::new("parking-spot-42", "", "parking")
+                .validate()
+                .is_ok()
+        );
+        assert_eq!(
+            Resource::new("", "", "parking").validate().unwrap_err(),
+            Error::InvalidResourceId("".to_string())
+        );
+        assert!(Resource::new("x".repeat(65), "", "").validate().is_err());
+        assert_eq!(
+            Resource::new("parking-lot", "", "parking")
+                .with_capacity(-1)
+                .validate()
+                .unwrap_err(),
+            Error::InvalidCapacity(-1)
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use chrono::{DateTime, Utc};
+use sqlx::postgres::types::PgRange;
+
+use crate::{
+    Error, FindAvailableQuery, ResourceGroup, Validator,
+    types::{get_timespan, validate_range},
+};
+
+impl ResourceGroup {
+    /// every active resource of `kind`
+    pub fn of_kind(kind: impl Into<String>) -> Self {
+        Self {
+            resource_ids: vec![],
+            kind: kind.into(),
+        }
+    }
+
+    /// the given resources, in order of preference
+    pub fn of_resources(resource_ids: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            resource_ids: resource_ids.into_iter().map(Into::into).collect(),
+            kind: String::new(),
+        }
+    }
+
+    pub fn get_kind(&self) -> Option<&str> {
+        (!self.kind.is_empty()).then_some(self.kind.as_str())
+    }
+}
+
+impl Validator for ResourceGroup {
+    fn validate(&self) -> Result<(), Error> {
+        if self.resource_ids.is_empty() && self.kind.is_empty() {
+            return Err(Error::InvalidResourceId("".to_string()));
+        }
+        if let Some(id) = self.resource_ids.iter().find(|id| id.is_empty()) {
+            return Err(Error::InvalidResourceId(id.clone()));
+        }
+        Ok(())
+    }
+}
+
+impl FindAvailableQuery {
+    pub fn get_timespan(&self) -> PgRange<DateTime<Utc>> {
+        get_timespan(self.start.as_ref(), self.end.as_ref())
+    }
+}
+
+impl Validator for FindAvailableQuery {
+    fn validate(&self) -> Result<(), Error> {
+        match &self.group {
+            Some(group) => group.validate()?,
+            None => return Err(Error::InvalidResourceId("".to_string())),
+        }
+        validate_range(self.start.as_ref(), self.end.as_ref())?;
+        Ok(())
+    }
+}
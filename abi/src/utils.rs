@@ -1,5 +1,5 @@
-use chrono::{DateTime, Utc};
-use prost_types::Timestamp;
+use chrono::{DateTime, TimeDelta, Utc};
+use prost_types::{Duration, Timestamp};
 
 pub fn convert_to_utc_time(ts: Timestamp) -> DateTime<Utc> {
     DateTime::from_timestamp(ts.seconds, ts.nanos as u32).unwrap_or_else(Utc::now)
@@ -11,3 +11,8 @@ pub fn convert_to_timestamp(dt: DateTime<Utc>) -> Timestamp {
         nanos: dt.timestamp_subsec_nanos() as i32,
     }
 }
+
+/// `None` if the duration is out of range
+pub fn convert_to_time_delta(d: Duration) -> Option<TimeDelta> {
+    TimeDelta::try_seconds(d.seconds)?.checked_add(&TimeDelta::nanoseconds(d.nanos as i64))
+}
@@ -0,0 +1,5 @@
+-- Add down migration script here
+
+ALTER TABLE rsvp.reservation_changes DROP CONSTRAINT reservation_changes_pkey;
+ALTER SEQUENCE rsvp.reservation_changes_id_seq AS INTEGER;
+ALTER TABLE rsvp.reservation_changes ALTER COLUMN id TYPE INTEGER;
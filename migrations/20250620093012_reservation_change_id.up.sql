@@ -0,0 +1,6 @@
+-- Add up migration script here
+
+-- change ids are exposed to subscribers as a resumable cursor
+ALTER TABLE rsvp.reservation_changes ALTER COLUMN id TYPE BIGINT;
+ALTER SEQUENCE rsvp.reservation_changes_id_seq AS BIGINT;
+ALTER TABLE rsvp.reservation_changes ADD CONSTRAINT reservation_changes_pkey PRIMARY KEY (id);
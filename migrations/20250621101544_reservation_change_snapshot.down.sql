@@ -0,0 +1,21 @@
+-- Add down migration script here
+
+CREATE OR REPLACE FUNCTION rsvp.reservation_trigger() RETURNS trigger
+AS $$
+BEGIN
+    IF  TG_OP = 'INSERT' THEN
+        INSERT INTO rsvp.reservation_changes (reservation_id, op) VALUES (NEW.id, 'create');
+    ELSIF  TG_OP = 'UPDATE' THEN
+        IF OLD.status != NEW.status THEN
+            INSERT INTO rsvp.reservation_changes (reservation_id, op) VALUES (NEW.id, 'update');
+        END IF;
+    ELSIF  TG_OP = 'DELETE' THEN
+        INSERT INTO rsvp.reservation_changes (reservation_id, op) VALUES (OLD.id, 'delete');
+    END IF;
+    -- notify a channel called reservation_update
+    NOTIFY reservation_update;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+ALTER TABLE rsvp.reservation_changes DROP COLUMN snapshot;
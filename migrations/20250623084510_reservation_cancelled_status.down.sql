@@ -0,0 +1,3 @@
+-- Add down migration script here
+
+-- postgres can't drop a value from an enum, 'cancelled' is kept
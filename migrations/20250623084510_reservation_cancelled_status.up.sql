@@ -0,0 +1,5 @@
+-- Add up migration script here
+
+-- the new value can't be used in the transaction adding it, so the constraint
+-- referencing it lives in the next migration
+ALTER TYPE rsvp.reservation_status ADD VALUE IF NOT EXISTS 'cancelled';
@@ -0,0 +1,8 @@
+-- Add down migration script here
+
+ALTER TABLE rsvp.reservation_changes DROP COLUMN created_at;
+
+-- fails if cancelled reservations overlap with others, they need to be removed first
+ALTER TABLE rsvp.reservations DROP CONSTRAINT reservation_conflict;
+ALTER TABLE rsvp.reservations ADD CONSTRAINT reservation_conflict
+    EXCLUDE USING gist (resource_id WITH =, timespan WITH &&);
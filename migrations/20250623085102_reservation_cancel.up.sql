@@ -0,0 +1,9 @@
+-- Add up migration script here
+
+-- cancelled reservations are kept for history but no longer hold their slot
+ALTER TABLE rsvp.reservations DROP CONSTRAINT reservation_conflict;
+ALTER TABLE rsvp.reservations ADD CONSTRAINT reservation_conflict
+    EXCLUDE USING gist (resource_id WITH =, timespan WITH &&) WHERE (status != 'cancelled');
+
+-- record when each change happened
+ALTER TABLE rsvp.reservation_changes ADD COLUMN created_at TIMESTAMPTZ NOT NULL DEFAULT now();
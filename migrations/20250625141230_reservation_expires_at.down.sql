@@ -0,0 +1,3 @@
+-- Add down migration script here
+
+ALTER TABLE rsvp.reservations DROP COLUMN expires_at;
@@ -0,0 +1,6 @@
+-- Add up migration script here
+
+-- pending reservations may hold their window only until expires_at
+ALTER TABLE rsvp.reservations ADD COLUMN expires_at TIMESTAMPTZ;
+
+CREATE INDEX idx_reservations_expires_at ON rsvp.reservations USING btree (expires_at) WHERE status = 'pending';
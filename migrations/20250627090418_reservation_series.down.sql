@@ -0,0 +1,4 @@
+-- Add down migration script here
+
+ALTER TABLE rsvp.reservations DROP COLUMN series_id;
+DROP TABLE rsvp.reservation_series;
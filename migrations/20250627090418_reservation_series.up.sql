@@ -0,0 +1,14 @@
+-- Add up migration script here
+
+-- a series groups the occurrences expanded from a recurrence rule
+CREATE TABLE rsvp.reservation_series (
+    id BIGSERIAL NOT NULL,
+    rrule TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+
+    CONSTRAINT reservation_series_pkey PRIMARY KEY (id)
+);
+
+ALTER TABLE rsvp.reservations ADD COLUMN series_id BIGINT REFERENCES rsvp.reservation_series (id);
+
+CREATE INDEX idx_reservations_series_id ON rsvp.reservations USING btree (series_id, lower(timespan));
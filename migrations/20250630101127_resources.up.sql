@@ -0,0 +1,23 @@
+-- Add up migration script here
+
+CREATE TABLE rsvp.resources (
+    id VARCHAR(64) NOT NULL,
+    name VARCHAR(128) NOT NULL,
+    kind VARCHAR(64) NOT NULL DEFAULT '',
+    attributes JSONB NOT NULL DEFAULT '{}',
+    -- inactive resources are kept for existing reservations but can't be reserved
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+
+    CONSTRAINT resources_pkey PRIMARY KEY (id)
+);
+
+CREATE INDEX idx_resources_kind ON rsvp.resources USING btree (kind);
+
+-- register the resources already reserved so that existing reservations stay valid
+INSERT INTO rsvp.resources (id, name)
+SELECT DISTINCT resource_id, resource_id FROM rsvp.reservations;
+
+ALTER TABLE rsvp.reservations ADD CONSTRAINT reservations_resource_id_fkey
+    FOREIGN KEY (resource_id) REFERENCES rsvp.resources (id);
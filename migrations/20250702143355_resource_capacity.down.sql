@@ -0,0 +1,8 @@
+-- Add down migration script here
+
+ALTER TABLE rsvp.reservations DROP CONSTRAINT reservation_conflict;
+ALTER TABLE rsvp.reservations ADD CONSTRAINT reservation_conflict
+    EXCLUDE USING gist (resource_id WITH =, timespan WITH &&) WHERE (status != 'cancelled');
+
+ALTER TABLE rsvp.reservations DROP COLUMN pooled;
+ALTER TABLE rsvp.resources DROP COLUMN capacity;
@@ -0,0 +1,13 @@
+-- Add up migration script here
+
+-- number of reservations a resource admits at the same time
+ALTER TABLE rsvp.resources ADD COLUMN capacity INTEGER NOT NULL DEFAULT 1
+    CONSTRAINT resources_capacity_check CHECK (capacity > 0);
+
+-- reservations of resources with a capacity above one are checked against the capacity
+-- when inserted, while holding a lock on the resource, instead of by the exclusion constraint
+ALTER TABLE rsvp.reservations ADD COLUMN pooled BOOLEAN NOT NULL DEFAULT FALSE;
+
+ALTER TABLE rsvp.reservations DROP CONSTRAINT reservation_conflict;
+ALTER TABLE rsvp.reservations ADD CONSTRAINT reservation_conflict
+    EXCLUDE USING gist (resource_id WITH =, timespan WITH &&) WHERE (status != 'cancelled' AND NOT pooled);
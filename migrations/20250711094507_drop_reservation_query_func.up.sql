@@ -0,0 +1,4 @@
+-- Add up migration script here
+
+-- reservation queries are built by the reservation crate
+DROP FUNCTION rsvp.query(text, text, TSTZRANGE, rsvp.reservation_status[], integer, bool, integer, TIMESTAMPTZ, BIGINT, text);
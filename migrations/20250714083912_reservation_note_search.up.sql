@@ -0,0 +1,7 @@
+-- Add up migration script here
+
+-- searchable words of the note, kept in sync by postgres
+ALTER TABLE rsvp.reservations ADD COLUMN note_tsv tsvector
+    GENERATED ALWAYS AS (to_tsvector('english', coalesce(note, ''))) STORED;
+
+CREATE INDEX idx_reservations_note_tsv ON rsvp.reservations USING gin (note_tsv);
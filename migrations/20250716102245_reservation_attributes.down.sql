@@ -0,0 +1,4 @@
+-- Add down migration script here
+
+DROP INDEX rsvp.idx_reservations_attributes;
+ALTER TABLE rsvp.reservations DROP COLUMN attributes;
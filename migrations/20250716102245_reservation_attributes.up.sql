@@ -0,0 +1,6 @@
+-- Add up migration script here
+
+-- structured metadata of a reservation, filtered with containment queries
+ALTER TABLE rsvp.reservations ADD COLUMN attributes JSONB NOT NULL DEFAULT '{}';
+
+CREATE INDEX idx_reservations_attributes ON rsvp.reservations USING gin (attributes jsonb_path_ops);
@@ -0,0 +1,19 @@
+-- Add down migration script here
+
+CREATE OR REPLACE FUNCTION rsvp.reservation_trigger() RETURNS trigger
+AS $$
+BEGIN
+    IF  TG_OP = 'INSERT' THEN
+        INSERT INTO rsvp.reservation_changes (reservation_id, op, snapshot) VALUES (NEW.id, 'create', to_jsonb(NEW));
+    ELSIF  TG_OP = 'UPDATE' THEN
+        IF OLD.status != NEW.status THEN
+            INSERT INTO rsvp.reservation_changes (reservation_id, op, snapshot) VALUES (NEW.id, 'update', to_jsonb(NEW));
+        END IF;
+    ELSIF  TG_OP = 'DELETE' THEN
+        INSERT INTO rsvp.reservation_changes (reservation_id, op, snapshot) VALUES (OLD.id, 'delete', to_jsonb(OLD));
+    END IF;
+    -- notify a channel called reservation_update
+    NOTIFY reservation_update;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
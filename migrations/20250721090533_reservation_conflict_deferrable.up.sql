@@ -0,0 +1,7 @@
+-- Add up migration script here
+
+-- checked per statement unless deferred, so reservations can trade places in a transaction
+ALTER TABLE rsvp.reservations DROP CONSTRAINT reservation_conflict;
+ALTER TABLE rsvp.reservations ADD CONSTRAINT reservation_conflict
+    EXCLUDE USING gist (resource_id WITH =, timespan WITH &&) WHERE (status != 'cancelled' AND NOT pooled)
+    DEFERRABLE INITIALLY IMMEDIATE;
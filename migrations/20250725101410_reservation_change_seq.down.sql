@@ -0,0 +1,5 @@
+-- Add down migration script here
+
+DROP FUNCTION rsvp.publish_changes();
+DROP INDEX rsvp.idx_reservation_changes_unpublished;
+ALTER TABLE rsvp.reservation_changes DROP COLUMN seq;
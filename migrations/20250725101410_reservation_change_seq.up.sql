@@ -0,0 +1,27 @@
+-- Add up migration script here
+
+-- Change ids are handed out when a change is inserted, so a transaction can commit a lower
+-- id after a higher one has been read. Subscribers follow `seq` instead, which numbers the
+-- changes once committed, one publisher at a time, so it follows the commit order.
+ALTER TABLE rsvp.reservation_changes ADD COLUMN seq BIGINT;
+UPDATE rsvp.reservation_changes SET seq = id;
+ALTER TABLE rsvp.reservation_changes ADD CONSTRAINT reservation_changes_seq_key UNIQUE (seq);
+CREATE INDEX idx_reservation_changes_unpublished ON rsvp.reservation_changes (id) WHERE seq IS NULL;
+
+CREATE SEQUENCE rsvp.reservation_changes_seq_seq AS BIGINT OWNED BY rsvp.reservation_changes.seq;
+SELECT setval('rsvp.reservation_changes_seq_seq', COALESCE(MAX(id), 0) + 1, false)
+    FROM rsvp.reservation_changes;
+
+CREATE OR REPLACE FUNCTION rsvp.publish_changes() RETURNS void
+AS $$
+DECLARE
+    _id BIGINT;
+BEGIN
+    -- held until commit, so every number given here is visible before the next publisher runs
+    PERFORM pg_advisory_xact_lock('rsvp.reservation_changes'::regclass::oid::bigint);
+    FOR _id IN SELECT id FROM rsvp.reservation_changes WHERE seq IS NULL ORDER BY id LOOP
+        UPDATE rsvp.reservation_changes SET seq = nextval('rsvp.reservation_changes_seq_seq')
+            WHERE id = _id;
+    END LOOP;
+END;
+$$ LANGUAGE plpgsql;
@@ -0,0 +1,5 @@
+-- Add down migration script here
+
+ALTER TABLE rsvp.reservations
+    DROP COLUMN cancelled_by,
+    DROP COLUMN cancelled_at;
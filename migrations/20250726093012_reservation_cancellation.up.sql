@@ -0,0 +1,6 @@
+-- Add up migration script here
+
+-- who cancelled the reservation and when, the user is unknown for expired holds
+ALTER TABLE rsvp.reservations
+    ADD COLUMN cancelled_by VARCHAR(64),
+    ADD COLUMN cancelled_at TIMESTAMPTZ;
@@ -0,0 +1,3 @@
+-- Add down migration script here
+
+DROP FUNCTION rsvp.full_spans(text, TSTZRANGE, bigint, bool, bigint[]);
@@ -0,0 +1,26 @@
+-- Add down migration script here
+
+CREATE OR REPLACE FUNCTION rsvp.full_spans(
+    rid text,
+    during TSTZRANGE,
+    capacity bigint,
+    pooled_only bool DEFAULT FALSE,
+    excluded bigint[] DEFAULT '{}'
+) RETURNS SETOF TSTZRANGE AS $$
+    WITH counted AS (
+        SELECT timespan FROM rsvp.reservations
+        WHERE resource_id = rid AND timespan && during AND status != 'cancelled'
+            AND (pooled OR NOT pooled_only) AND id != ALL(excluded)
+    ), bounds AS (
+        SELECT DISTINCT unnest(ARRAY[
+            GREATEST(lower(timespan), lower(during)),
+            LEAST(upper(timespan), upper(during))
+        ]) AS at
+        FROM counted
+    ), spans AS (
+        SELECT tstzrange(at, lead(at) OVER (ORDER BY at)) AS span FROM bounds
+    )
+    SELECT span FROM spans
+    WHERE NOT upper_inf(span)
+        AND (SELECT count(*) FROM counted WHERE timespan @> lower(span)) >= capacity
+$$ LANGUAGE sql STABLE;
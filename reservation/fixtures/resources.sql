@@ -0,0 +1,6 @@
+INSERT INTO rsvp.resources (id, name, kind) VALUES
+    ('ocean-view-room-777', 'Ocean view room 777', 'room'),
+    ('ocean-view-room-778', 'Ocean view room 778', 'room'),
+    ('meeting-room-1', 'Meeting room 1', 'room'),
+    ('parking-spot-42', 'Parking spot 42', 'parking'),
+    ('projector-3', 'Projector 3', 'equipment');
@@ -0,0 +1,322 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicI64, Ordering},
+};
+use std::time::Duration;
+
+use abi::SubscribeResponse;
+use sqlx::{PgPool, postgres::PgListener};
+use tokio::sync::{broadcast, mpsc};
+use tokio_stream::wrappers::ReceiverStream;
+
+use crate::{ChangeFeed, ChangeStream, ReservationManager};
+
+/// channel notified by the `rsvp.reservation_trigger` trigger
+const CHANGE_CHANNEL: &str = "reservation_update";
+/// number of changes kept for subscribers that fall behind
+const CHANGE_BUFFER_SIZE: usize = 1024;
+/// number of changes buffered for a single subscriber
+const SUBSCRIBER_BUFFER_SIZE: usize = 128;
+/// number of changes read from the database at once
+const CHANGE_BATCH_SIZE: i64 = 256;
+/// wait time before retrying after the database is unavailable
+const RETRY_INTERVAL: Duration = Duration::from_secs(1);
+
+impl ChangeFeed {
+    /// listen for reservation changes and broadcast them to every subscriber
+    pub async fn start(manager: &ReservationManager) -> Result<Self, abi::Error> {
+        let pool = manager.pool.clone();
+        let mut listener = PgListener::connect_with(&pool).await?;
+        listener.listen(CHANGE_CHANNEL).await?;
+
+        // only changes that happen after the feed starts are broadcast
+        publish_changes(&pool).await?;
+        let last_id: i64 =
+            sqlx::query_scalar("SELECT COALESCE(MAX(seq), 0) FROM rsvp.reservation_changes")
+                .fetch_one(&pool)
+                .await?;
+        let last_id = Arc::new(AtomicI64::new(last_id));
+
+        let (sender, _) = broadcast::channel(CHANGE_BUFFER_SIZE);
+        tokio::spawn(run(listener, pool.clone(), sender.clone(), last_id.clone()));
+        Ok(Self {
+            pool,
+            sender,
+            last_id,
+        })
+    }
+
+    /// stream every change after `since` (or after this call if `None`), followed by live changes
+    pub fn subscribe(&self, since: Option<i64>) -> ChangeStream {
+        let mut live = self.sender.subscribe();
+        let mut last_id = since.unwrap_or_else(|| self.last_id.load(Ordering::Acquire));
+        let pool = self.pool.clone();
+        let (tx, rx) = mpsc::channel(SUBSCRIBER_BUFFER_SIZE);
+
+        tokio::spawn(async move {
+            loop {
+                // catch up from the database: on the first run this replays the changes after
+                // `since`, afterwards it recovers the changes dropped when falling behind
+                loop {
+                    let changes = match fetch_changes(&pool, last_id).await {
+                        Ok(changes) => changes,
+                        Err(e) => {
+                            let _ = tx.send(Err(e.into())).await;
+                            return;
+                        }
+                    };
+                    let done = (changes.len() as i64) < CHANGE_BATCH_SIZE;
+                    for change in changes {
+                        last_id = change.change_id;
+                        if tx.send(Ok(change)).await.is_err() {
+                            return;
+                        }
+                    }
+                    if done {
+                        break;
+                    }
+                }
+
+                loop {
+                    match live.recv().await {
+                        // already delivered by the catch up
+                        Ok(change) if change.change_id <= last_id => continue,
+                        Ok(change) => {
+                            last_id = change.change_id;
+                            if tx.send(Ok(change)).await.is_err() {
+                                return;
+                            }
+                        }
+                        Err(broadcast::error::RecvError::Lagged(_)) => break,
+                        Err(broadcast::error::RecvError::Closed) => return,
+                    }
+                }
+            }
+        });
+
+        Box::pin(ReceiverStream::new(rx))
+    }
+}
+
+async fn run(
+    mut listener: PgListener,
+    pool: PgPool,
+    sender: broadcast::Sender<SubscribeResponse>,
+    last_id: Arc<AtomicI64>,
+) {
+    loop {
+        // `Ok(None)` means the connection was lost and will be re-established on the next call.
+        // Changes are read by sequence, so anything committed in the meantime is still picked up.
+        if let Err(e) = listener.try_recv().await {
+            if matches!(e, sqlx::Error::PoolClosed) {
+                break;
+            }
+            tracing::warn!("failed to receive reservation notification: {e}");
+            tokio::time::sleep(RETRY_INTERVAL).await;
+            continue;
+        }
+
+        loop {
+            let changes = match fetch_changes(&pool, last_id.load(Ordering::Acquire)).await {
+                Ok(changes) => changes,
+                Err(e) => {
+                    tracing::warn!("failed to fetch reservation changes: {e}");
+                    break;
+                }
+            };
+            let done = (changes.len() as i64) < CHANGE_BATCH_SIZE;
+            for change in changes {
+                let id = change.change_id;
+                // sending only fails when nobody subscribes, which is fine
+                let _ = sender.send(change);
+                // updated after sending, so a new subscriber never skips a change
+                // it hasn't received
+                last_id.store(id, Ordering::Release);
+            }
+            if done {
+                break;
+            }
+        }
+    }
+}
+
+/// number the committed changes in commit order, see `rsvp.publish_changes`
+async fn publish_changes(pool: &PgPool) -> Result<(), sqlx::Error> {
+    sqlx::query("SELECT rsvp.publish_changes()")
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+/// fetch at most `CHANGE_BATCH_SIZE` changes published after `last_id` in order, with the
+/// reservation as of the change. Changes committed since the last call are published first
+async fn fetch_changes(pool: &PgPool, last_id: i64) -> Result<Vec<SubscribeResponse>, sqlx::Error> {
+    publish_changes(pool).await?;
+    sqlx::query_as(
+        "SELECT c.seq AS change_id, c.reservation_id, c.op, r.*
+         FROM rsvp.reservation_changes c
+         LEFT JOIN LATERAL jsonb_populate_record(NULL::rsvp.reservations, c.snapshot) r ON TRUE
+         WHERE c.seq > $1
+         ORDER BY c.seq
+         LIMIT $2",
+    )
+    .bind(last_id)
+    .bind(CHANGE_BATCH_SIZE)
+    .fetch_all(pool)
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use abi::{Reservation, ReservationUpdateType};
+    use futures::StreamExt;
+
+    use super::*;
+    use crate::Rsvp;
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn change_feed_should_broadcast_changes(pool: PgPool) {
+        let manager = ReservationManager::new(pool);
+        let feed = ChangeFeed::start(&manager).await.unwrap();
+        let mut stream = feed.subscribe(None);
+
+        let rsvp = manager.reserve(shur_reservation()).await.unwrap();
+        let change = stream.next().await.unwrap().unwrap();
+        assert_eq!(change.op, ReservationUpdateType::Create as i32);
+        assert_eq!(change.reservation.unwrap(), rsvp);
+
+        let rsvp = manager.change_status(rsvp.id, None).await.unwrap();
+        let change = stream.next().await.unwrap().unwrap();
+        assert_eq!(change.op, ReservationUpdateType::Update as i32);
+        assert_eq!(change.reservation.unwrap(), rsvp);
+
+        manager.delete(rsvp.id).await.unwrap();
+        let change = stream.next().await.unwrap().unwrap();
+        assert_eq!(change.op, ReservationUpdateType::Delete as i32);
+        assert_eq!(change.reservation.unwrap(), rsvp);
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn replayed_changes_should_keep_deleted_reservation(pool: PgPool) {
+        let manager = ReservationManager::new(pool);
+        let feed = ChangeFeed::start(&manager).await.unwrap();
+
+        let rsvp = manager.reserve(shur_reservation()).await.unwrap();
+        manager.delete(rsvp.id).await.unwrap();
+
+        // both events are read back after the reservation is gone
+        let mut stream = feed.subscribe(Some(0));
+        let created = stream.next().await.unwrap().unwrap();
+        assert_eq!(created.op, ReservationUpdateType::Create as i32);
+        assert_eq!(created.reservation.unwrap(), rsvp);
+
+        let deleted = stream.next().await.unwrap().unwrap();
+        assert_eq!(deleted.op, ReservationUpdateType::Delete as i32);
+        assert_eq!(deleted.reservation.unwrap(), rsvp);
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn change_feed_should_replay_changes_since_cursor(pool: PgPool) {
+        let manager = ReservationManager::new(pool);
+        let feed = ChangeFeed::start(&manager).await.unwrap();
+
+        let rsvp = manager.reserve(shur_reservation()).await.unwrap();
+        let mut stream = feed.subscribe(Some(0));
+        let created = stream.next().await.unwrap().unwrap();
+        assert_eq!(created.op, ReservationUpdateType::Create as i32);
+
+        manager.change_status(rsvp.id, None).await.unwrap();
+        let updated = stream.next().await.unwrap().unwrap();
+        assert_eq!(updated.op, ReservationUpdateType::Update as i32);
+        assert!(updated.change_id > created.change_id);
+
+        // resuming from the create event only replays the update
+        let mut stream = feed.subscribe(Some(created.change_id));
+        let change = stream.next().await.unwrap().unwrap();
+        assert_eq!(change, updated);
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn change_feed_should_replay_in_batches(pool: PgPool) {
+        let manager = ReservationManager::new(pool.clone());
+        let feed = ChangeFeed::start(&manager).await.unwrap();
+
+        let count = CHANGE_BATCH_SIZE * 2 + 1;
+        sqlx::query(
+            "INSERT INTO rsvp.reservations (user_id, resource_id, timespan, note)
+             SELECT 'shurid', 'ocean-view-room-777',
+                    tstzrange('2025-01-01'::timestamptz + n * interval '1 day',
+                              '2025-01-01'::timestamptz + (n + 1) * interval '1 day'), ''
+             FROM generate_series(1, $1) AS n",
+        )
+        .bind(count)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let changes: Vec<_> = feed.subscribe(Some(0)).take(count as usize).collect().await;
+        let ids: Vec<_> = changes.into_iter().map(|c| c.unwrap().change_id).collect();
+        assert_eq!(ids.len() as i64, count);
+        assert!(ids.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn change_committed_late_should_still_be_delivered(pool: PgPool) {
+        let manager = ReservationManager::new(pool.clone());
+        let feed = ChangeFeed::start(&manager).await.unwrap();
+        let mut stream = feed.subscribe(None);
+
+        // the first transaction records its change first but commits last
+        let mut slow = pool.begin().await.unwrap();
+        let slow_id: i64 = sqlx::query_scalar(
+            "INSERT INTO rsvp.reservations (user_id, resource_id, timespan, note)
+             VALUES ('aliceid', 'ocean-view-room-778', '[2025-05-13, 2025-05-15)', '')
+             RETURNING id",
+        )
+        .fetch_one(&mut *slow)
+        .await
+        .unwrap();
+        let fast = manager.reserve(shur_reservation()).await.unwrap();
+        let change = stream.next().await.unwrap().unwrap();
+        assert_eq!(change.reservation.unwrap().id, fast.id);
+
+        slow.commit().await.unwrap();
+        let late = stream.next().await.unwrap().unwrap();
+        assert_eq!(late.reservation.unwrap().id, slow_id);
+        assert!(late.change_id > change.change_id);
+
+        // replays follow the same order
+        let mut replay = feed.subscribe(Some(0));
+        let first = replay.next().await.unwrap().unwrap();
+        let second = replay.next().await.unwrap().unwrap();
+        assert_eq!(
+            (first.change_id, second.change_id),
+            (change.change_id, late.change_id)
+        );
+    }
+
+    fn shur_reservation() -> Reservation {
+        Reservation::new_pending(
+            "shurid",
+            "ocean-view-room-777",
+            "2025-05-13T15:00:00-0700".parse().unwrap(),
+            "2025-05-15T12:00:00-0700".parse().unwrap(),
+            "this is shur's reservation",
+        )
+    }
+}
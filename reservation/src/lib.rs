@@ -1,33 +1,191 @@
+mod changes;
 mod manager;
+mod query;
+mod resource;
 
-use abi::ReservationId;
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::{Arc, atomic::AtomicI64};
+
+use abi::{ReservationId, ReservationStatus, SeriesScope};
+use chrono::{DateTime, Utc};
+use futures::Stream;
 use sqlx::PgPool;
+use tokio::sync::broadcast;
+
+pub type RsvpStream = Pin<Box<dyn Stream<Item = Result<abi::Reservation, abi::Error>> + Send>>;
+pub type ChangeStream =
+    Pin<Box<dyn Stream<Item = Result<abi::SubscribeResponse, abi::Error>> + Send>>;
 
 // interact with the database asynchronously
+// updates given an `expected_version` fail with `Error::VersionMismatch` if the reservation
+// has been updated since the caller read that version
 #[async_trait::async_trait]
 pub trait Rsvp {
     /// make a reservation
     async fn reserve(&self, rsvp: abi::Reservation) -> Result<abi::Reservation, abi::Error>;
-    /// change reservation status
-    async fn change_status(&self, id: ReservationId) -> Result<abi::Reservation, abi::Error>;
+    /// make several reservations in one transaction, if any of them fails none is made
+    async fn reserve_many(
+        &self,
+        rsvps: Vec<abi::Reservation>,
+    ) -> Result<Vec<abi::Reservation>, abi::Error>;
+    /// reserve every occurrence of the recurrence rule `rrule`, `rsvp` being the first one.
+    /// Returns the reserved occurrences, linked by a series id, and the conflicting ones.
+    /// Unless `allow_partial` is set nothing is reserved if any occurrence conflicts
+    async fn reserve_recurring(
+        &self,
+        rsvp: abi::Reservation,
+        rrule: &str,
+        allow_partial: bool,
+    ) -> Result<(Vec<abi::Reservation>, Vec<abi::Reservation>), abi::Error>;
+    /// block the resource for the reservation window, optionally cancelling the overlapping
+    /// pending reservations. Returns the blocked reservation and the cancelled ones
+    async fn block(
+        &self,
+        rsvp: abi::Reservation,
+        evict_pending: bool,
+    ) -> Result<(abi::Reservation, Vec<abi::Reservation>), abi::Error>;
+    /// change reservation status from pending to confirmed
+    async fn change_status(
+        &self,
+        id: ReservationId,
+        expected_version: Option<i64>,
+    ) -> Result<abi::Reservation, abi::Error>;
+    /// change reservation status from `from` to `to`, if the transition is allowed.
+    /// Reservations are cancelled with `cancel` instead
+    async fn transition(
+        &self,
+        id: ReservationId,
+        from: ReservationStatus,
+        to: ReservationStatus,
+        expected_version: Option<i64>,
+    ) -> Result<abi::Reservation, abi::Error>;
     /// update note
     async fn update_note(
         &self,
         id: ReservationId,
         note: String,
+        expected_version: Option<i64>,
+    ) -> Result<abi::Reservation, abi::Error>;
+    /// patch the attributes, the keys in `remove` are dropped before `set` is merged in
+    async fn update_attributes(
+        &self,
+        id: ReservationId,
+        set: HashMap<String, String>,
+        remove: Vec<String>,
+        expected_version: Option<i64>,
+    ) -> Result<abi::Reservation, abi::Error>;
+    /// update note of the reservation and the occurrences of its series covered by `scope`,
+    /// `expected_version` being the one of the given reservation
+    async fn update_series_note(
+        &self,
+        id: ReservationId,
+        note: String,
+        scope: SeriesScope,
+        expected_version: Option<i64>,
+    ) -> Result<Vec<abi::Reservation>, abi::Error>;
+    /// move the reservation to a new window, and to `resource_id` if given, keeping its id.
+    /// Cancelled reservations can't be moved
+    async fn reschedule(
+        &self,
+        id: ReservationId,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        resource_id: Option<String>,
+        expected_version: Option<i64>,
     ) -> Result<abi::Reservation, abi::Error>;
+    /// exchange the resources of two reservations, and their windows if `swap_windows` is
+    /// set, in one transaction. Returns both reservations after the swap, neither may be cancelled
+    async fn swap(
+        &self,
+        id_a: ReservationId,
+        id_b: ReservationId,
+        swap_windows: bool,
+        expected_version_a: Option<i64>,
+        expected_version_b: Option<i64>,
+    ) -> Result<(abi::Reservation, abi::Reservation), abi::Error>;
     /// get reservation
     async fn get(&self, id: ReservationId) -> Result<abi::Reservation, abi::Error>;
-    /// delete reservation
-    async fn delete(&self, id: ReservationId) -> Result<(), abi::Error>;
-    /// query reservations
-    async fn query(
+    /// cancel reservation on behalf of `cancelled_by`, the reservation is kept but no longer
+    /// blocks its window
+    async fn cancel(
+        &self,
+        id: ReservationId,
+        cancelled_by: String,
+        expected_version: Option<i64>,
+    ) -> Result<abi::Reservation, abi::Error>;
+    /// cancel the reservation and the occurrences of its series covered by `scope`.
+    /// Occurrences that can't be cancelled are skipped, the reservation itself is always returned
+    async fn cancel_series(
         &self,
-        query: abi::ReservationQuery,
+        id: ReservationId,
+        scope: SeriesScope,
+        cancelled_by: String,
+        expected_version: Option<i64>,
     ) -> Result<Vec<abi::Reservation>, abi::Error>;
+    /// cancel the pending reservations whose hold has expired
+    async fn expire_pending(&self) -> Result<Vec<abi::Reservation>, abi::Error>;
+    /// delete reservation
+    async fn delete(&self, id: ReservationId) -> Result<abi::Reservation, abi::Error>;
+    /// query a page of reservations, with the cursor of the next page if there may be more
+    async fn query(&self, query: abi::ReservationQuery)
+    -> Result<abi::ReservationPage, abi::Error>;
+    /// query every matching reservation regardless of paging, streaming rows as they are read
+    /// from the database
+    async fn query_stream(&self, query: abi::ReservationQuery) -> Result<RsvpStream, abi::Error>;
+    /// find the resources of the group that can be reserved for the whole query window
+    async fn find_available(
+        &self,
+        query: abi::FindAvailableQuery,
+    ) -> Result<Vec<String>, abi::Error>;
+    /// reserve the first available resource of the group for the reservation window
+    async fn reserve_any(
+        &self,
+        group: abi::ResourceGroup,
+        rsvp: abi::Reservation,
+    ) -> Result<abi::Reservation, abi::Error>;
+    /// find the intervals of the query window in which each resource can be reserved. Every
+    /// resource must exist and be active
+    async fn availability(
+        &self,
+        query: abi::AvailabilityQuery,
+    ) -> Result<Vec<abi::FreeInterval>, abi::Error>;
 }
 
-#[derive(Debug)]
+// manage the resources that can be reserved
+#[async_trait::async_trait]
+pub trait Catalog {
+    /// register a new resource, it is created active
+    async fn create(&self, resource: abi::Resource) -> Result<abi::Resource, abi::Error>;
+    /// get resource
+    async fn get(&self, id: &str) -> Result<abi::Resource, abi::Error>;
+    /// update name, kind, attributes and capacity of the resource
+    async fn update(&self, resource: abi::Resource) -> Result<abi::Resource, abi::Error>;
+    /// list resources ordered by id, optionally of a single kind
+    async fn list(
+        &self,
+        kind: Option<String>,
+        include_inactive: bool,
+    ) -> Result<Vec<abi::Resource>, abi::Error>;
+    /// deactivate resource, its existing reservations are kept but it can't be reserved anymore
+    async fn deactivate(&self, id: &str) -> Result<abi::Resource, abi::Error>;
+}
+
+#[derive(Debug, Clone)]
 pub struct ReservationManager {
     pool: PgPool,
 }
+
+#[derive(Debug, Clone)]
+pub struct ResourceManager {
+    pool: PgPool,
+}
+
+/// broadcasts reservation changes recorded by the database trigger
+#[derive(Debug, Clone)]
+pub struct ChangeFeed {
+    pool: PgPool,
+    sender: broadcast::Sender<abi::SubscribeResponse>,
+    /// sequence of the last change broadcast
+    last_id: Arc<AtomicI64>,
+}
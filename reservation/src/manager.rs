@@ -1,64 +1,352 @@
-use abi::{ReservationId, ReservationQuery, ReservationStatus, Validator};
+use std::collections::HashMap;
+
+use abi::{
+    AvailabilityQuery, DbConfig, FindAvailableQuery, RecurrenceRule, ReservationConflict,
+    ReservationConflictInfo, ReservationCursor, ReservationId, ReservationQuery, ReservationStatus,
+    ReservationWindow, RsvpStatus, SeriesScope, Validator, convert_to_timestamp,
+};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use sqlx::Row;
+use futures::StreamExt;
 use sqlx::postgres::types::PgRange;
+use sqlx::postgres::{PgConnection, PgPoolOptions};
+use sqlx::types::Json;
+use sqlx::{Acquire, Row};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+
+use crate::{
+    ReservationManager, Rsvp, RsvpStream,
+    query::{build_query, build_stream_query},
+};
 
-use crate::{ReservationManager, Rsvp};
+const STREAM_BUFFER_SIZE: usize = 128;
 
 #[async_trait]
 impl Rsvp for ReservationManager {
     async fn reserve(&self, rsvp: abi::Reservation) -> Result<abi::Reservation, abi::Error> {
         rsvp.validate()?;
-        let mut rsvp_clone = rsvp.clone();
+        let status = rsvp.get_initial_status()?;
+        let mut tx = self.pool.begin().await?;
+        let rsvp = insert_reservation(&mut tx, rsvp, status).await?;
+        tx.commit().await?;
+        Ok(rsvp)
+    }
+
+    async fn reserve_many(
+        &self,
+        rsvps: Vec<abi::Reservation>,
+    ) -> Result<Vec<abi::Reservation>, abi::Error> {
+        for rsvp in &rsvps {
+            rsvp.validate()?;
+        }
+
+        // the transaction is rolled back when dropped on error
+        let mut tx = self.pool.begin().await?;
+        let mut reserved = Vec::with_capacity(rsvps.len());
+        for rsvp in rsvps {
+            let status = rsvp.get_initial_status()?;
+            reserved.push(insert_reservation(&mut tx, rsvp, status).await?);
+        }
+        tx.commit().await?;
+
+        Ok(reserved)
+    }
 
-        let timespan: PgRange<DateTime<Utc>> = rsvp.get_timespan();
-        let status = ReservationStatus::try_from(rsvp.status).unwrap_or(ReservationStatus::Pending);
+    async fn reserve_recurring(
+        &self,
+        rsvp: abi::Reservation,
+        rrule: &str,
+        allow_partial: bool,
+    ) -> Result<(Vec<abi::Reservation>, Vec<abi::Reservation>), abi::Error> {
+        let rule: RecurrenceRule = rrule.parse()?;
+        let occurrences = rsvp.expand(&rule)?;
+        let status = rsvp.get_initial_status()?;
 
-        // generate an insert sql for the reservation
-        let id = sqlx::query(
-            "INSERT INTO rsvp.reservations
-                 (user_id, resource_id, timespan, note, status)
-                 VALUES ($1, $2, $3, $4, $5::rsvp.reservation_status)
-                 RETURNING id",
+        let mut tx = self.pool.begin().await?;
+        let series_id: i64 = sqlx::query_scalar(
+            "INSERT INTO rsvp.reservation_series (rrule) VALUES ($1) RETURNING id",
         )
-        .bind(rsvp.user_id)
-        .bind(rsvp.resource_id)
-        .bind(timespan)
-        .bind(rsvp.note)
-        .bind(status.to_string())
-        .fetch_one(&self.pool)
-        .await?
-        .get(0);
-        rsvp_clone.id = id;
-        Ok(rsvp_clone)
-    }
-
-    async fn change_status(&self, id: ReservationId) -> Result<abi::Reservation, abi::Error> {
-        // if current status is pending, change it to confirmed, otherwise do nothing
-        id.validate()?;
-        let rsvp: abi::Reservation = sqlx::query_as(
-            "UPDATE rsvp.reservations SET status = 'confirmed' WHERE id = $1 AND status = 'pending' RETURNING *"
-        ).bind(id).fetch_one(&self.pool).await?;
+        .bind(rrule)
+        .fetch_one(&mut *tx)
+        .await?;
 
-        Ok(rsvp)
+        let mut reserved = Vec::with_capacity(occurrences.len());
+        let mut conflicts = vec![];
+        for occurrence in occurrences {
+            // each occurrence is inserted in a savepoint, so a conflict only rolls back itself
+            let mut savepoint = tx.begin().await?;
+            let rsvp = abi::Reservation {
+                series_id,
+                ..occurrence.clone()
+            };
+            match insert_reservation(&mut savepoint, rsvp, status).await {
+                Ok(rsvp) => {
+                    savepoint.commit().await?;
+                    reserved.push(rsvp);
+                }
+                Err(abi::Error::ConflictReservation(_)) => {
+                    savepoint.rollback().await?;
+                    conflicts.push(occurrence);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        // dropping the transaction rolls back the series and the reserved occurrences
+        if reserved.is_empty() || (!conflicts.is_empty() && !allow_partial) {
+            return Ok((vec![], conflicts));
+        }
+        tx.commit().await?;
+
+        Ok((reserved, conflicts))
+    }
+
+    async fn block(
+        &self,
+        rsvp: abi::Reservation,
+        evict_pending: bool,
+    ) -> Result<(abi::Reservation, Vec<abi::Reservation>), abi::Error> {
+        rsvp.validate()?;
+        let mut tx = self.pool.begin().await?;
+
+        let evicted = if evict_pending {
+            sqlx::query_as(
+                "UPDATE rsvp.reservations
+                 SET status = 'cancelled', cancelled_by = $3, cancelled_at = now()
+                 WHERE resource_id = $1 AND timespan && $2 AND status = 'pending'
+                 RETURNING *",
+            )
+            .bind(&rsvp.resource_id)
+            .bind(rsvp.get_timespan())
+            .bind(&rsvp.user_id)
+            .fetch_all(&mut *tx)
+            .await?
+        } else {
+            vec![]
+        };
+
+        // other overlapping reservations make the insert fail with a conflict
+        let rsvp = insert_reservation(&mut tx, rsvp, ReservationStatus::Blocked).await?;
+        tx.commit().await?;
+
+        Ok((rsvp, evicted))
+    }
+
+    async fn change_status(
+        &self,
+        id: ReservationId,
+        expected_version: Option<i64>,
+    ) -> Result<abi::Reservation, abi::Error> {
+        self.transition(
+            id,
+            ReservationStatus::Pending,
+            ReservationStatus::Confirmed,
+            expected_version,
+        )
+        .await
+    }
+
+    async fn transition(
+        &self,
+        id: ReservationId,
+        from: ReservationStatus,
+        to: ReservationStatus,
+        expected_version: Option<i64>,
+    ) -> Result<abi::Reservation, abi::Error> {
+        // cancellations go through `cancel`, which records who cancelled
+        if to == ReservationStatus::Cancelled {
+            return Err(abi::Error::InvalidTransition {
+                current: from,
+                requested: to,
+            });
+        }
+        self.update_status(id, Some(from), to, None, expected_version)
+            .await
     }
 
     async fn update_note(
         &self,
         id: crate::ReservationId,
         note: String,
+        expected_version: Option<i64>,
     ) -> Result<abi::Reservation, abi::Error> {
         id.validate()?;
+        let mut tx = self.pool.begin().await?;
+        lock_version(&mut tx, id, expected_version).await?;
         let rsvp: abi::Reservation =
             sqlx::query_as("UPDATE rsvp.reservations SET note = $1 WHERE id = $2 RETURNING *")
                 .bind(note)
                 .bind(id)
-                .fetch_one(&self.pool)
+                .fetch_one(&mut *tx)
+                .await?;
+        tx.commit().await?;
+        Ok(rsvp)
+    }
+
+    async fn reschedule(
+        &self,
+        id: ReservationId,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        resource_id: Option<String>,
+        expected_version: Option<i64>,
+    ) -> Result<abi::Reservation, abi::Error> {
+        id.validate()?;
+        let mut tx = self.pool.begin().await?;
+        let rsvp: abi::Reservation =
+            sqlx::query_as("SELECT * FROM rsvp.reservations WHERE id = $1 FOR UPDATE")
+                .bind(id)
+                .fetch_one(&mut *tx)
                 .await?;
+        check_version(expected_version, rsvp.version)?;
+        check_movable(&rsvp)?;
+        let rsvp = abi::Reservation {
+            resource_id: resource_id.unwrap_or(rsvp.resource_id),
+            start: Some(convert_to_timestamp(start)),
+            end: Some(convert_to_timestamp(end)),
+            ..rsvp
+        };
+        rsvp.validate()?;
+
+        // the exclusion constraint rejects windows taken on resources with a capacity of one
+        let pooled = check_capacity(&mut tx, &rsvp, &[id]).await?;
+        let rsvp: abi::Reservation = sqlx::query_as(
+            "UPDATE rsvp.reservations SET resource_id = $2, timespan = $3, pooled = $4
+             WHERE id = $1 RETURNING *",
+        )
+        .bind(id)
+        .bind(&rsvp.resource_id)
+        .bind(rsvp.get_timespan())
+        .bind(pooled)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(rsvp)
+    }
+
+    async fn swap(
+        &self,
+        id_a: ReservationId,
+        id_b: ReservationId,
+        swap_windows: bool,
+        expected_version_a: Option<i64>,
+        expected_version_b: Option<i64>,
+    ) -> Result<(abi::Reservation, abi::Reservation), abi::Error> {
+        id_a.validate()?;
+        id_b.validate()?;
+        if id_a == id_b {
+            return Err(abi::Error::InvalidReservationId(id_b));
+        }
+
+        let mut tx = self.pool.begin().await?;
+        // each reservation is in the other's place until both are moved
+        sqlx::query("SET CONSTRAINTS rsvp.reservation_conflict DEFERRED")
+            .execute(&mut *tx)
+            .await?;
+        let rsvps: Vec<abi::Reservation> = sqlx::query_as(
+            "SELECT * FROM rsvp.reservations WHERE id = ANY($1) ORDER BY id FOR UPDATE",
+        )
+        .bind([id_a, id_b])
+        .fetch_all(&mut *tx)
+        .await?;
+        let find = |id| {
+            rsvps
+                .iter()
+                .find(|rsvp| rsvp.id == id)
+                .ok_or(abi::Error::NotFound)
+        };
+        let (a, b) = (find(id_a)?, find(id_b)?);
+        check_version(expected_version_a, a.version)?;
+        check_version(expected_version_b, b.version)?;
+        check_movable(a)?;
+        check_movable(b)?;
+        let trade = |from: &abi::Reservation, to: &abi::Reservation| abi::Reservation {
+            resource_id: to.resource_id.clone(),
+            start: if swap_windows { to.start } else { from.start },
+            end: if swap_windows { to.end } else { from.end },
+            ..from.clone()
+        };
+        let mut moved = vec![trade(a, b), trade(b, a)];
+        // resources are locked in order, so concurrent swaps can't deadlock
+        moved.sort_by(|x, y| x.resource_id.cmp(&y.resource_id));
+
+        let mut swapped = HashMap::new();
+        for rsvp in moved {
+            rsvp.validate()?;
+            let pooled = check_capacity(&mut tx, &rsvp, &[id_a, id_b]).await?;
+            let rsvp: abi::Reservation = sqlx::query_as(
+                "UPDATE rsvp.reservations SET resource_id = $2, timespan = $3, pooled = $4
+                 WHERE id = $1 RETURNING *",
+            )
+            .bind(rsvp.id)
+            .bind(&rsvp.resource_id)
+            .bind(rsvp.get_timespan())
+            .bind(pooled)
+            .fetch_one(&mut *tx)
+            .await?;
+            swapped.insert(rsvp.id, rsvp);
+        }
+
+        // the deferred exclusion constraint is checked here
+        tx.commit().await?;
+        Ok((
+            swapped.remove(&id_a).unwrap(),
+            swapped.remove(&id_b).unwrap(),
+        ))
+    }
+
+    async fn update_attributes(
+        &self,
+        id: crate::ReservationId,
+        set: HashMap<String, String>,
+        remove: Vec<String>,
+        expected_version: Option<i64>,
+    ) -> Result<abi::Reservation, abi::Error> {
+        id.validate()?;
+        let mut tx = self.pool.begin().await?;
+        lock_version(&mut tx, id, expected_version).await?;
+        let rsvp: abi::Reservation = sqlx::query_as(
+            "UPDATE rsvp.reservations SET attributes = (attributes - $2::text[]) || $3
+             WHERE id = $1 RETURNING *",
+        )
+        .bind(id)
+        .bind(remove)
+        .bind(Json(set))
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
         Ok(rsvp)
     }
 
+    async fn update_series_note(
+        &self,
+        id: ReservationId,
+        note: String,
+        scope: SeriesScope,
+        expected_version: Option<i64>,
+    ) -> Result<Vec<abi::Reservation>, abi::Error> {
+        let mut tx = self.pool.begin().await?;
+        let series = lock_series(&mut tx, id, scope, expected_version).await?;
+        let (series_id, since) = series.unzip();
+        let mut rsvps: Vec<abi::Reservation> = sqlx::query_as(
+            "UPDATE rsvp.reservations SET note = $1
+             WHERE id = $2
+                OR (series_id = $3 AND ($4::timestamptz IS NULL OR lower(timespan) >= $4))
+             RETURNING *",
+        )
+        .bind(note)
+        .bind(id)
+        .bind(series_id)
+        .bind(since.flatten())
+        .fetch_all(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        rsvps.sort_by_key(|rsvp| rsvp.id);
+        Ok(rsvps)
+    }
+
     async fn get(&self, id: ReservationId) -> Result<abi::Reservation, abi::Error> {
         id.validate()?;
         let rsvp: abi::Reservation =
@@ -70,65 +358,548 @@ impl Rsvp for ReservationManager {
         Ok(rsvp)
     }
 
-    async fn delete(&self, id: crate::ReservationId) -> Result<(), abi::Error> {
-        id.validate()?;
-        let rsvp = sqlx::query_as("DELETE FROM rsvp.reservations WHERE id = $1 RETURNING *")
-            .bind(id)
-            .fetch_one(&self.pool)
+    async fn cancel(
+        &self,
+        id: ReservationId,
+        cancelled_by: String,
+        expected_version: Option<i64>,
+    ) -> Result<abi::Reservation, abi::Error> {
+        if cancelled_by.is_empty() {
+            return Err(abi::Error::InvalidUserId(cancelled_by));
+        }
+        self.update_status(
+            id,
+            None,
+            ReservationStatus::Cancelled,
+            Some(&cancelled_by),
+            expected_version,
+        )
+        .await
+    }
+
+    async fn cancel_series(
+        &self,
+        id: ReservationId,
+        scope: SeriesScope,
+        cancelled_by: String,
+        expected_version: Option<i64>,
+    ) -> Result<Vec<abi::Reservation>, abi::Error> {
+        if cancelled_by.is_empty() {
+            return Err(abi::Error::InvalidUserId(cancelled_by));
+        }
+        let mut tx = self.pool.begin().await?;
+        let Some((series_id, since)) = lock_series(&mut tx, id, scope, expected_version).await?
+        else {
+            // already locked at the expected version
+            let rsvp = set_status(
+                &mut tx,
+                id,
+                None,
+                ReservationStatus::Cancelled,
+                Some(&cancelled_by),
+                None,
+            )
             .await?;
-        Ok(rsvp)
+            tx.commit().await?;
+            return Ok(vec![rsvp]);
+        };
+
+        let rsvps: Vec<abi::Reservation> = sqlx::query_as(
+            "SELECT * FROM rsvp.reservations
+             WHERE series_id = $1 AND ($2::timestamptz IS NULL OR lower(timespan) >= $2)
+             ORDER BY id FOR UPDATE",
+        )
+        .bind(series_id)
+        .bind(since)
+        .fetch_all(&mut *tx)
+        .await?;
+        let ids: Vec<_> = rsvps
+            .iter()
+            .filter(|rsvp| {
+                ReservationStatus::try_from(rsvp.status)
+                    .is_ok_and(|status| status.can_transition_to(ReservationStatus::Cancelled))
+            })
+            .map(|rsvp| rsvp.id)
+            .collect();
+        let mut cancelled: Vec<abi::Reservation> = sqlx::query_as(
+            "UPDATE rsvp.reservations
+             SET status = 'cancelled', cancelled_by = $2, cancelled_at = now()
+             WHERE id = ANY($1) RETURNING *",
+        )
+        .bind(&ids)
+        .bind(&cancelled_by)
+        .fetch_all(&mut *tx)
+        .await?;
+        tx.commit().await?;
+
+        // the reservation itself is returned even if it was left as is
+        if !ids.contains(&id) {
+            let rsvp = rsvps.into_iter().find(|rsvp| rsvp.id == id);
+            cancelled.extend(rsvp);
+        }
+        cancelled.sort_by_key(|rsvp| rsvp.id);
+        Ok(cancelled)
     }
 
-    async fn query(&self, query: ReservationQuery) -> Result<Vec<abi::Reservation>, abi::Error> {
-        let user_id = str_to_option(&query.user_id);
-        let resource_id = str_to_option(&query.resource_id);
-        let range = query.get_timespan();
-        let status =
-            ReservationStatus::try_from(query.status).unwrap_or(ReservationStatus::Pending);
+    async fn expire_pending(&self) -> Result<Vec<abi::Reservation>, abi::Error> {
         let rsvps = sqlx::query_as(
-            "SELECT * FROM rsvp.query($1, $2, $3, $4::rsvp.reservation_status, $5, $6, $7)",
+            "UPDATE rsvp.reservations SET status = 'cancelled', cancelled_at = now()
+             WHERE status = 'pending' AND expires_at <= now()
+             RETURNING *",
         )
-        .bind(user_id)
-        .bind(resource_id)
-        .bind(range)
-        .bind(status.to_string())
-        .bind(query.page)
-        .bind(query.desc)
-        .bind(query.page_size)
         .fetch_all(&self.pool)
         .await?;
-
         Ok(rsvps)
     }
+
+    async fn delete(&self, id: crate::ReservationId) -> Result<abi::Reservation, abi::Error> {
+        id.validate()?;
+        let rsvp: abi::Reservation =
+            sqlx::query_as("DELETE FROM rsvp.reservations WHERE id = $1 RETURNING *")
+                .bind(id)
+                .fetch_one(&self.pool)
+                .await?;
+        Ok(rsvp)
+    }
+
+    async fn query(&self, query: ReservationQuery) -> Result<abi::ReservationPage, abi::Error> {
+        query.validate()?;
+        let page_size = query.get_page_size() as usize;
+        let reservations: Vec<abi::Reservation> = build_query(&query)
+            .build_query_as()
+            .fetch_all(&self.pool)
+            .await?;
+
+        // a full page may be followed by more reservations
+        let next_cursor = match reservations.last() {
+            Some(last) if reservations.len() == page_size => {
+                ReservationCursor::from(last).to_string()
+            }
+            _ => String::new(),
+        };
+        Ok(abi::ReservationPage {
+            reservations,
+            next_cursor,
+        })
+    }
+
+    async fn query_stream(&self, query: ReservationQuery) -> Result<RsvpStream, abi::Error> {
+        query.validate()?;
+        let pool = self.pool.clone();
+        let (tx, rx) = mpsc::channel(STREAM_BUFFER_SIZE);
+
+        // rows are forwarded as soon as they are read from the connection, the bounded
+        // channel makes the database reader wait for slow consumers
+        tokio::spawn(async move {
+            let mut builder = build_stream_query(&query);
+            let mut rsvps = builder.build_query_as().fetch(&pool);
+            while let Some(ret) = rsvps.next().await {
+                let is_err = ret.is_err();
+                if tx.send(ret.map_err(abi::Error::from)).await.is_err() || is_err {
+                    // receiver dropped or the query failed, stop reading
+                    break;
+                }
+            }
+        });
+
+        Ok(Box::pin(ReceiverStream::new(rx)))
+    }
+
+    async fn find_available(&self, query: FindAvailableQuery) -> Result<Vec<String>, abi::Error> {
+        query.validate()?;
+        let mut conn = self.pool.acquire().await?;
+        available_resources(&mut conn, &query).await
+    }
+
+    async fn reserve_any(
+        &self,
+        group: abi::ResourceGroup,
+        rsvp: abi::Reservation,
+    ) -> Result<abi::Reservation, abi::Error> {
+        let query = FindAvailableQuery {
+            group: Some(group),
+            start: rsvp.start,
+            end: rsvp.end,
+        };
+        query.validate()?;
+        let status = rsvp.get_initial_status()?;
+
+        let mut tx = self.pool.begin().await?;
+        for resource_id in available_resources(&mut tx, &query).await? {
+            let rsvp = abi::Reservation {
+                resource_id,
+                ..rsvp.clone()
+            };
+            rsvp.validate()?;
+
+            // the resource may have been taken since it was found, then try the next one
+            let mut savepoint = tx.begin().await?;
+            match insert_reservation(&mut savepoint, rsvp, status).await {
+                Ok(rsvp) => {
+                    savepoint.commit().await?;
+                    tx.commit().await?;
+                    return Ok(rsvp);
+                }
+                Err(abi::Error::ConflictReservation(_) | abi::Error::UnavailableResource(_)) => {
+                    savepoint.rollback().await?;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        Err(abi::Error::NoAvailableResource)
+    }
+
+    async fn availability(
+        &self,
+        query: AvailabilityQuery,
+    ) -> Result<Vec<abi::FreeInterval>, abi::Error> {
+        query.validate()?;
+
+        // unknown or inactive resources would otherwise look fully booked
+        let active: Vec<String> =
+            sqlx::query_scalar("SELECT id FROM rsvp.resources WHERE id = ANY($1) AND active")
+                .bind(&query.resource_ids)
+                .fetch_all(&self.pool)
+                .await?;
+        if let Some(id) = query.resource_ids.iter().find(|id| !active.contains(id)) {
+            return Err(abi::Error::UnavailableResource(id.clone()));
+        }
+
+        // what is left of the window once the spans using up the capacity are removed is free
+        let intervals = sqlx::query_as(
+            "SELECT res.id AS resource_id, lower(f.free) AS start, upper(f.free) AS end
+             FROM rsvp.resources res
+             CROSS JOIN LATERAL unnest(tstzmultirange($2) - (
+                 SELECT COALESCE(range_agg(s.span), '{}')
+                 FROM rsvp.full_spans(res.id, $2, res.capacity) AS s(span)
+             )) AS f(free)
+             WHERE res.id = ANY($1) AND res.active AND upper(f.free) - lower(f.free) >= $3
+             ORDER BY res.id, lower(f.free)",
+        )
+        .bind(&query.resource_ids)
+        .bind(query.get_timespan())
+        .bind(query.get_min_duration()?)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(intervals)
+    }
 }
 
 impl ReservationManager {
     pub fn new(pool: sqlx::PgPool) -> Self {
         Self { pool }
     }
+
+    /// move the reservation to `to` if its current status is `expected` (any status if `None`)
+    /// and the transition is allowed, see `set_status`
+    async fn update_status(
+        &self,
+        id: ReservationId,
+        expected: Option<ReservationStatus>,
+        to: ReservationStatus,
+        cancelled_by: Option<&str>,
+        expected_version: Option<i64>,
+    ) -> Result<abi::Reservation, abi::Error> {
+        id.validate()?;
+        let mut tx = self.pool.begin().await?;
+        let rsvp = set_status(&mut tx, id, expected, to, cancelled_by, expected_version).await?;
+        tx.commit().await?;
+        Ok(rsvp)
+    }
+
+    pub async fn from_config(config: &DbConfig) -> Result<Self, abi::Error> {
+        let pool = PgPoolOptions::default()
+            .max_connections(config.max_connections)
+            .connect(&config.url())
+            .await?;
+        Ok(Self::new(pool))
+    }
+}
+
+async fn insert_reservation(
+    conn: &mut PgConnection,
+    mut rsvp: abi::Reservation,
+    status: ReservationStatus,
+) -> Result<abi::Reservation, abi::Error> {
+    rsvp.status = status as i32;
+    let pooled = check_capacity(conn, &rsvp, &[]).await?;
+
+    // generate an insert sql for the reservation
+    let row = sqlx::query(
+        "INSERT INTO rsvp.reservations
+             (user_id, resource_id, timespan, note, status, expires_at, series_id, pooled, attributes)
+             VALUES ($1, $2, $3, $4, $5::rsvp.reservation_status, $6, $7, $8, $9)
+             RETURNING id, version",
+    )
+    .bind(&rsvp.user_id)
+    .bind(&rsvp.resource_id)
+    .bind(rsvp.get_timespan())
+    .bind(&rsvp.note)
+    .bind(status.to_string())
+    .bind(rsvp.get_expires_at())
+    .bind((rsvp.series_id != 0).then_some(rsvp.series_id))
+    .bind(pooled)
+    .bind(Json(&rsvp.attributes))
+    .fetch_one(&mut *conn)
+    .await?;
+
+    rsvp.id = row.get("id");
+    rsvp.version = row.get("version");
+    Ok(rsvp)
+}
+
+/// move the reservation to `to` if its current status is `expected` (any status if `None`)
+/// and the transition is allowed. A cancellation records `cancelled_by` and the time
+async fn set_status(
+    conn: &mut PgConnection,
+    id: ReservationId,
+    expected: Option<ReservationStatus>,
+    to: ReservationStatus,
+    cancelled_by: Option<&str>,
+    expected_version: Option<i64>,
+) -> Result<abi::Reservation, abi::Error> {
+    // lock the row so the status can't change between the check and the update
+    let (current, version): (RsvpStatus, i64) =
+        sqlx::query_as("SELECT status, version FROM rsvp.reservations WHERE id = $1 FOR UPDATE")
+            .bind(id)
+            .fetch_one(&mut *conn)
+            .await?;
+    check_version(expected_version, version)?;
+    let current = ReservationStatus::from(current);
+
+    if expected.is_some_and(|expected| expected != current) || !current.can_transition_to(to) {
+        return Err(abi::Error::InvalidTransition {
+            current,
+            requested: to,
+        });
+    }
+
+    let rsvp = sqlx::query_as(
+        "UPDATE rsvp.reservations SET status = $2::rsvp.reservation_status,
+            cancelled_by = $3, cancelled_at = CASE WHEN $2 = 'cancelled' THEN now() END
+         WHERE id = $1 RETURNING *",
+    )
+    .bind(id)
+    .bind(to.to_string())
+    .bind(cancelled_by)
+    .fetch_one(&mut *conn)
+    .await?;
+    Ok(rsvp)
+}
+
+/// lock the reservation at the expected version like `lock_version`, and return its series id
+/// and the start of the first occurrence covered by `scope` (all occurrences if `None`).
+/// Returns `None` if only the reservation itself is covered
+async fn lock_series(
+    conn: &mut PgConnection,
+    id: ReservationId,
+    scope: SeriesScope,
+    expected_version: Option<i64>,
+) -> Result<Option<(i64, Option<DateTime<Utc>>)>, abi::Error> {
+    id.validate()?;
+    let (series_id, start, version): (Option<i64>, DateTime<Utc>, i64) = sqlx::query_as(
+        "SELECT series_id, lower(timespan), version FROM rsvp.reservations
+         WHERE id = $1 FOR UPDATE",
+    )
+    .bind(id)
+    .fetch_one(&mut *conn)
+    .await?;
+    check_version(expected_version, version)?;
+
+    let Some(series_id) = series_id.filter(|_| scope != SeriesScope::Occurrence) else {
+        return Ok(None);
+    };
+    let since = (scope == SeriesScope::Following).then_some(start);
+    Ok(Some((series_id, since)))
+}
+
+/// lock the reservation so it can't be updated by others, and make sure it is at the
+/// expected version if given
+async fn lock_version(
+    conn: &mut PgConnection,
+    id: ReservationId,
+    expected: Option<i64>,
+) -> Result<(), abi::Error> {
+    let actual: i64 =
+        sqlx::query_scalar("SELECT version FROM rsvp.reservations WHERE id = $1 FOR UPDATE")
+            .bind(id)
+            .fetch_one(&mut *conn)
+            .await?;
+    check_version(expected, actual)
+}
+
+/// a cancelled reservation no longer holds its window, so it can't be moved either
+fn check_movable(rsvp: &abi::Reservation) -> Result<(), abi::Error> {
+    if rsvp.status == ReservationStatus::Cancelled as i32 {
+        return Err(abi::Error::InvalidTransition {
+            current: ReservationStatus::Cancelled,
+            requested: ReservationStatus::Cancelled,
+        });
+    }
+    Ok(())
+}
+
+fn check_version(expected: Option<i64>, actual: i64) -> Result<(), abi::Error> {
+    match expected {
+        Some(expected) if expected != actual => {
+            Err(abi::Error::VersionMismatch { expected, actual })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Lock the resource of `rsvp` and check its window fits in the capacity left by the other
+/// reservations, `moved_ids` being the reservations being moved, which aren't counted.
+/// Returns whether the resource is pooled
+async fn check_capacity(
+    conn: &mut PgConnection,
+    rsvp: &abi::Reservation,
+    moved_ids: &[ReservationId],
+) -> Result<bool, abi::Error> {
+    let timespan: PgRange<DateTime<Utc>> = rsvp.get_timespan();
+
+    // lock the resource until the reservation is committed, so it can't be deactivated and
+    // concurrent reservations of a pooled resource are counted one after another
+    let capacity: Option<i32> = sqlx::query_scalar(
+        "SELECT capacity FROM rsvp.resources WHERE id = $1 AND active FOR NO KEY UPDATE",
+    )
+    .bind(&rsvp.resource_id)
+    .fetch_optional(&mut *conn)
+    .await?;
+    let Some(capacity) = capacity else {
+        return Err(abi::Error::UnavailableResource(rsvp.resource_id.clone()));
+    };
+    let pooled = capacity > 1;
+    // a block takes up the whole capacity, so it only fits where nothing else is reserved
+    let units = if rsvp.status == ReservationStatus::Blocked as i32 {
+        capacity
+    } else {
+        1
+    };
+
+    // find where the window would exceed the capacity. Reservations of a resource with a
+    // capacity of one are checked by the exclusion constraint, except the ones made while
+    // its capacity was larger
+    let full: Option<(DateTime<Utc>, DateTime<Utc>)> = sqlx::query_as(
+        "SELECT lower(span), upper(span) FROM rsvp.full_spans($1, $2, $3, $4, $5) AS span
+         ORDER BY lower(span)
+         LIMIT 1",
+    )
+    .bind(&rsvp.resource_id)
+    .bind(timespan)
+    .bind((capacity - units + 1) as i64)
+    .bind(!pooled)
+    .bind(moved_ids)
+    .fetch_optional(&mut *conn)
+    .await?;
+
+    if let Some((start, end)) = full {
+        let window = |start, end| ReservationWindow {
+            resource_id: rsvp.resource_id.clone(),
+            start,
+            end,
+        };
+        let (new_start, new_end) = rsvp.get_window();
+        return Err(abi::Error::ConflictReservation(
+            ReservationConflictInfo::Parsed(ReservationConflict {
+                new: window(new_start, new_end),
+                old: window(start, end),
+            }),
+        ));
+    }
+
+    Ok(pooled)
 }
 
-fn str_to_option(s: &str) -> Option<&str> {
-    if s.is_empty() { None } else { Some(s) }
+/// active resources of the group with room left for the whole query window
+async fn available_resources(
+    conn: &mut PgConnection,
+    query: &FindAvailableQuery,
+) -> Result<Vec<String>, abi::Error> {
+    let group = query.group.as_ref().unwrap();
+    let resource_ids = sqlx::query_scalar(
+        "SELECT res.id FROM rsvp.resources res
+         WHERE res.active AND (res.id = ANY($1) OR res.kind = $2)
+            AND NOT EXISTS (SELECT 1 FROM rsvp.full_spans(res.id, $3, res.capacity))
+         ORDER BY array_position($1, res.id::text), res.id",
+    )
+    .bind(&group.resource_ids)
+    .bind(group.get_kind())
+    .bind(query.get_timespan())
+    .fetch_all(conn)
+    .await?;
+    Ok(resource_ids)
 }
+
 #[cfg(test)]
 mod tests {
     use abi::{
-        Reservation, ReservationConflict, ReservationConflictInfo, ReservationQueryBuilder,
-        ReservationStatus, ReservationWindow,
+        AvailabilityQueryBuilder, QueryMatchMode, Reservation, ReservationQueryBuilder, Resource,
+        ResourceGroup,
     };
+    use chrono::TimeDelta;
     use prost_types::Timestamp;
     use sqlx::PgPool;
 
     use super::*;
+    use crate::{Catalog, ResourceManager};
 
-    #[sqlx::test(migrations = "../migrations")]
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
     async fn reserve_should_work_for_valid_window(pool: PgPool) {
         let (rsvp, _) = make_shur_reservation(pool).await;
         assert!(rsvp.id != 0);
     }
 
-    #[sqlx::test(migrations = "../migrations")]
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn reserve_without_status_should_be_pending(pool: PgPool) {
+        let manager = ReservationManager::new(pool);
+        let rsvp = Reservation {
+            status: ReservationStatus::Unknown as i32,
+            ..shur_reservation_for("ocean-view-room-777")
+        };
+        let rsvp = manager.reserve(rsvp).await.unwrap();
+        assert_eq!(rsvp.status, ReservationStatus::Pending as i32);
+
+        let rsvp = manager
+            .cancel(rsvp.id, "shurid".into(), None)
+            .await
+            .unwrap();
+        assert_eq!(rsvp.status, ReservationStatus::Cancelled as i32);
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn reserve_should_reject_blocked_or_cancelled_status(pool: PgPool) {
+        let manager = ReservationManager::new(pool);
+        for requested in [ReservationStatus::Blocked, ReservationStatus::Cancelled] {
+            let rsvp = Reservation {
+                status: requested as i32,
+                ..shur_reservation_for("ocean-view-room-777")
+            };
+            let err = manager.reserve(rsvp).await.unwrap_err();
+            assert_eq!(
+                err,
+                abi::Error::InvalidTransition {
+                    current: ReservationStatus::Unknown,
+                    requested,
+                }
+            );
+        }
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
     async fn reserve_conflict_reservation_should_reject(pool: PgPool) {
         let (_, manager) = make_shur_reservation(pool).await;
         let someone = Reservation::new_pending(
@@ -157,34 +928,218 @@ mod tests {
         assert_eq!(err, abi::Error::ConflictReservation(info))
     }
 
-    #[sqlx::test(migrations = "../migrations")]
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn reserve_many_should_reserve_all(pool: PgPool) {
+        let manager = ReservationManager::new(pool);
+        let rsvps = manager
+            .reserve_many(vec![
+                shur_reservation_for("ocean-view-room-777"),
+                shur_reservation_for("parking-spot-42"),
+                shur_reservation_for("projector-3"),
+            ])
+            .await
+            .unwrap();
+
+        assert_eq!(rsvps.len(), 3);
+        for rsvp in rsvps {
+            assert_eq!(manager.get(rsvp.id).await.unwrap(), rsvp);
+        }
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn reserve_many_conflict_should_roll_back_all(pool: PgPool) {
+        let (_, manager) = make_reservation(
+            pool,
+            "somebody",
+            "parking-spot-42",
+            "2025-05-14T15:00:00-0700",
+            "2025-05-16T12:00:00-0700",
+            "test2",
+        )
+        .await;
+
+        let err = manager
+            .reserve_many(vec![
+                shur_reservation_for("ocean-view-room-777"),
+                shur_reservation_for("parking-spot-42"),
+            ])
+            .await
+            .unwrap_err();
+
+        let info = ReservationConflictInfo::Parsed(ReservationConflict {
+            new: ReservationWindow {
+                resource_id: "parking-spot-42".to_string(),
+                start: "2025-05-13T15:00:00-0700".parse().unwrap(),
+                end: "2025-05-15T12:00:00-0700".parse().unwrap(),
+            },
+            old: ReservationWindow {
+                resource_id: "parking-spot-42".to_string(),
+                start: "2025-05-14T15:00:00-0700".parse().unwrap(),
+                end: "2025-05-16T12:00:00-0700".parse().unwrap(),
+            },
+        });
+        assert_eq!(err, abi::Error::ConflictReservation(info));
+
+        // the room reserved before the conflict was rolled back
+        let query = ReservationQueryBuilder::default()
+            .resource_id("ocean-view-room-777")
+            .start("2025-05-12T15:00:00-0700".parse::<Timestamp>().unwrap())
+            .end("2025-05-16T12:00:00-0700".parse::<Timestamp>().unwrap())
+            .status(ReservationStatus::Pending as i32)
+            .build()
+            .unwrap();
+        assert!(manager.query(query).await.unwrap().reservations.is_empty());
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
     async fn reserve_change_status_should_work(pool: PgPool) {
         let (rsvp, manager) = make_shur_reservation(pool).await;
-        let rsvp = manager.change_status(rsvp.id).await.unwrap();
+        let rsvp = manager.change_status(rsvp.id, None).await.unwrap();
         assert_eq!(rsvp.status, ReservationStatus::Confirmed as i32);
     }
 
-    #[sqlx::test(migrations = "../migrations")]
-    async fn reserve_change_status_not_pending_should_do_nothging(pool: PgPool) {
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn reserve_change_status_not_pending_should_reject(pool: PgPool) {
         let (rsvp, manager) = make_shur_reservation(pool).await;
-        let rsvp = manager.change_status(rsvp.id).await.unwrap();
+        let rsvp = manager.change_status(rsvp.id, None).await.unwrap();
+
+        // change status again should be rejected as an invalid transition
+        let ret = manager.change_status(rsvp.id, None).await.unwrap_err();
+        assert_eq!(
+            ret,
+            abi::Error::InvalidTransition {
+                current: ReservationStatus::Confirmed,
+                requested: ReservationStatus::Confirmed,
+            }
+        );
+    }
 
-        // change status again should do nothing
-        let ret = manager.change_status(rsvp.id).await.unwrap_err();
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn change_status_for_missing_reservation_should_return_not_found(pool: PgPool) {
+        let manager = ReservationManager::new(pool);
+        let ret = manager.change_status(42, None).await.unwrap_err();
         assert_eq!(ret, abi::Error::NotFound);
     }
 
-    #[sqlx::test(migrations = "../migrations")]
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn transition_should_check_current_status(pool: PgPool) {
+        let (rsvp, manager) = make_shur_reservation(pool).await;
+        let ret = manager
+            .transition(
+                rsvp.id,
+                ReservationStatus::Confirmed,
+                ReservationStatus::Confirmed,
+                None,
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(
+            ret,
+            abi::Error::InvalidTransition {
+                current: ReservationStatus::Pending,
+                requested: ReservationStatus::Confirmed,
+            }
+        );
+
+        let rsvp = manager
+            .transition(
+                rsvp.id,
+                ReservationStatus::Pending,
+                ReservationStatus::Confirmed,
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(rsvp.status, ReservationStatus::Confirmed as i32);
+
+        // cancelling needs to know who cancels
+        let ret = manager
+            .transition(
+                rsvp.id,
+                ReservationStatus::Confirmed,
+                ReservationStatus::Cancelled,
+                None,
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(ret, abi::Error::InvalidTransition { .. }));
+        assert_eq!(manager.get(rsvp.id).await.unwrap(), rsvp);
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn stale_version_should_reject_update(pool: PgPool) {
+        let (rsvp, manager) = make_shur_reservation(pool).await;
+        assert_eq!(rsvp.version, 1);
+
+        let updated = manager
+            .update_note(rsvp.id, "late check-in".into(), Some(1))
+            .await
+            .unwrap();
+        assert_eq!(updated.version, 2);
+
+        // a second agent still holding version 1
+        let err = manager
+            .update_note(rsvp.id, "needs crib".into(), Some(1))
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err,
+            abi::Error::VersionMismatch {
+                expected: 1,
+                actual: 2
+            }
+        );
+        let err = manager.change_status(rsvp.id, Some(1)).await.unwrap_err();
+        assert!(matches!(err, abi::Error::VersionMismatch { .. }));
+        let err = manager
+            .cancel(rsvp.id, "shurid".into(), Some(1))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, abi::Error::VersionMismatch { .. }));
+        assert_eq!(manager.get(rsvp.id).await.unwrap(), updated);
+
+        let confirmed = manager.change_status(rsvp.id, Some(2)).await.unwrap();
+        assert_eq!(confirmed.version, 3);
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
     async fn update_note_should_work(pool: PgPool) {
         let (rsvp, manager) = make_shur_reservation(pool).await;
         let rsvp = manager
-            .update_note(rsvp.id, "update note".into())
+            .update_note(rsvp.id, "update note".into(), None)
             .await
             .unwrap();
 
         assert_eq!(rsvp.note, "update note");
     }
-    #[sqlx::test(migrations = "../migrations")]
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
     async fn get_reservation_should_work(pool: PgPool) {
         let (rsvp1, manager) = make_shur_reservation(pool).await;
         let rsvp2 = manager.get(rsvp1.id).await.unwrap();
@@ -192,7 +1147,10 @@ mod tests {
         assert_eq!(rsvp1, rsvp2);
     }
 
-    #[sqlx::test(migrations = "../migrations")]
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
     async fn delete_reservation_should_work(pool: PgPool) {
         let (rsvp, manager) = make_shur_reservation(pool).await;
         manager.delete(rsvp.id).await.unwrap();
@@ -200,18 +1158,852 @@ mod tests {
         assert_eq!(rsvp1, abi::Error::NotFound);
     }
 
-    #[sqlx::test(migrations = "../migrations")]
-    async fn query_reservations_should_work(pool: PgPool) {
-        let (rsvp, manager) = make_shur_reservation(pool).await;
-        let query = ReservationQueryBuilder::default()
-            .user_id("shurid")
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn cancel_reservation_should_keep_it_and_free_the_window(pool: PgPool) {
+        let (rsvp, manager) = make_shur_reservation(pool.clone()).await;
+        let err = manager.cancel(rsvp.id, "".into(), None).await.unwrap_err();
+        assert_eq!(err, abi::Error::InvalidUserId("".to_string()));
+
+        let rsvp = manager
+            .cancel(rsvp.id, "frontdeskid".into(), None)
+            .await
+            .unwrap();
+        assert_eq!(rsvp.status, ReservationStatus::Cancelled as i32);
+        assert_eq!(rsvp.cancelled_by, "frontdeskid");
+        assert!(rsvp.get_cancelled_at().unwrap() <= Utc::now());
+        assert_eq!(manager.get(rsvp.id).await.unwrap(), rsvp);
+
+        // the same window can be reserved again
+        let (other, _) = make_reservation(
+            pool,
+            "somebody",
+            "ocean-view-room-777",
+            "2025-05-14T15:00:00-0700",
+            "2025-05-16T12:00:00-0700",
+            "test2",
+        )
+        .await;
+        assert!(other.id != 0);
+
+        // cancel again should be rejected
+        let ret = manager
+            .cancel(rsvp.id, "shurid".into(), None)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            ret,
+            abi::Error::InvalidTransition {
+                current: ReservationStatus::Cancelled,
+                requested: ReservationStatus::Cancelled,
+            }
+        );
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn block_should_conflict_with_existing_reservations(pool: PgPool) {
+        let (_, manager) = make_shur_reservation(pool).await;
+        let err = manager.block(maintenance_block(), false).await.unwrap_err();
+        assert!(matches!(err, abi::Error::ConflictReservation(_)));
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn block_should_evict_overlapping_pending_reservations(pool: PgPool) {
+        let (rsvp, manager) = make_shur_reservation(pool).await;
+        let (block, evicted) = manager.block(maintenance_block(), true).await.unwrap();
+        assert_eq!(block.status, ReservationStatus::Blocked as i32);
+        assert_eq!(evicted.len(), 1);
+        assert_eq!(evicted[0].id, rsvp.id);
+        assert_eq!(evicted[0].status, ReservationStatus::Cancelled as i32);
+        assert_eq!(evicted[0].cancelled_by, block.user_id);
+        assert!(evicted[0].cancelled_at.is_some());
+
+        // blocks can be told apart from customer bookings
+        let query = ReservationQueryBuilder::default()
+            .resource_id("ocean-view-room-777")
+            .start("2025-05-12T15:00:00-0700".parse::<Timestamp>().unwrap())
+            .end("2025-05-16T12:00:00-0700".parse::<Timestamp>().unwrap())
+            .status(ReservationStatus::Blocked as i32)
+            .build()
+            .unwrap();
+        assert_eq!(
+            manager.query(query).await.unwrap().reservations,
+            vec![block]
+        );
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn block_should_not_evict_confirmed_reservations(pool: PgPool) {
+        let (rsvp, manager) = make_shur_reservation(pool).await;
+        manager.change_status(rsvp.id, None).await.unwrap();
+
+        let err = manager.block(maintenance_block(), true).await.unwrap_err();
+        assert!(matches!(err, abi::Error::ConflictReservation(_)));
+        // nothing was cancelled as the block was rolled back
+        let rsvp = manager.get(rsvp.id).await.unwrap();
+        assert_eq!(rsvp.status, ReservationStatus::Confirmed as i32);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn block_should_take_up_pooled_resource(pool: PgPool) {
+        let manager = ReservationManager::new(pool);
+        let parking = Resource::new("parking-lot-a", "Parking lot A", "parking").with_capacity(3);
+        ResourceManager::from(&manager)
+            .create(parking)
+            .await
+            .unwrap();
+        let window = ("2025-05-13T08:00:00Z", "2025-05-13T12:00:00Z");
+
+        let confirmed = manager
+            .reserve(parking_reservation(window.0, window.1))
+            .await
+            .unwrap();
+        manager.change_status(confirmed.id, None).await.unwrap();
+        let block = || Reservation {
+            user_id: "adminid".to_string(),
+            ..parking_reservation(window.0, window.1)
+        };
+        // the lot isn't full, but the confirmed reservation still overlaps the block
+        let err = manager.block(block(), true).await.unwrap_err();
+        assert!(matches!(err, abi::Error::ConflictReservation(_)));
+
+        manager
+            .cancel(confirmed.id, "shurid".into(), None)
+            .await
+            .unwrap();
+        let pending = manager
+            .reserve(parking_reservation(window.0, window.1))
+            .await
+            .unwrap();
+        let (_, evicted) = manager.block(block(), true).await.unwrap();
+        assert_eq!(evicted.len(), 1);
+        assert_eq!(evicted[0].id, pending.id);
+
+        // no unit of the lot is left for the blocked window
+        let err = manager
+            .reserve(parking_reservation(
+                "2025-05-13T10:00:00Z",
+                "2025-05-13T11:00:00Z",
+            ))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, abi::Error::ConflictReservation(_)));
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn expire_pending_should_cancel_expired_holds(pool: PgPool) {
+        let manager = ReservationManager::new(pool);
+        let expired = manager
+            .reserve(
+                Reservation::new_pending(
+                    "shurid",
+                    "ocean-view-room-777",
+                    "2025-05-13T15:00:00-0700".parse().unwrap(),
+                    "2025-05-15T12:00:00-0700".parse().unwrap(),
+                    "expired hold",
+                )
+                .with_expiration(Utc::now() - TimeDelta::minutes(1)),
+            )
+            .await
+            .unwrap();
+        let held = manager
+            .reserve(
+                Reservation::new_pending(
+                    "shurid",
+                    "ocean-view-room-778",
+                    "2025-05-13T15:00:00-0700".parse().unwrap(),
+                    "2025-05-15T12:00:00-0700".parse().unwrap(),
+                    "active hold",
+                )
+                .with_expiration(Utc::now() + TimeDelta::minutes(15)),
+            )
+            .await
+            .unwrap();
+
+        let rsvps = manager.expire_pending().await.unwrap();
+        assert_eq!(rsvps.len(), 1);
+        assert_eq!(rsvps[0].id, expired.id);
+        assert_eq!(rsvps[0].status, ReservationStatus::Cancelled as i32);
+        assert!(rsvps[0].expires_at.is_some());
+        // expired holds are cancelled by nobody in particular
+        assert!(rsvps[0].cancelled_by.is_empty());
+        assert!(rsvps[0].cancelled_at.is_some());
+
+        let held = manager.get(held.id).await.unwrap();
+        assert_eq!(held.status, ReservationStatus::Pending as i32);
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn reserve_recurring_should_reserve_every_occurrence(pool: PgPool) {
+        let manager = ReservationManager::new(pool);
+        let (rsvps, conflicts) = manager
+            .reserve_recurring(standup_reservation(), "FREQ=WEEKLY;COUNT=12", false)
+            .await
+            .unwrap();
+
+        assert!(conflicts.is_empty());
+        assert_eq!(rsvps.len(), 12);
+        assert!(rsvps[0].series_id != 0);
+        assert!(
+            rsvps
+                .iter()
+                .all(|rsvp| rsvp.series_id == rsvps[0].series_id)
+        );
+        assert_eq!(
+            rsvps[11].start,
+            Some("2025-08-19T10:00:00Z".parse().unwrap())
+        );
+        assert_eq!(manager.get(rsvps[11].id).await.unwrap(), rsvps[11]);
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn reserve_recurring_should_report_conflicting_occurrences(pool: PgPool) {
+        // takes the room on the third occurrence
+        let (taken, manager) = make_reservation(
+            pool,
+            "somebody",
+            "meeting-room-1",
+            "2025-06-17T10:15:00Z",
+            "2025-06-17T11:00:00Z",
+            "taken",
+        )
+        .await;
+
+        let (rsvps, conflicts) = manager
+            .reserve_recurring(standup_reservation(), "FREQ=WEEKLY;COUNT=4", false)
+            .await
+            .unwrap();
+        assert!(rsvps.is_empty());
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(
+            conflicts[0].start,
+            Some("2025-06-17T10:00:00Z".parse().unwrap())
+        );
+
+        // nothing was reserved, the first occurrence is still free
+        let (rsvps, conflicts) = manager
+            .reserve_recurring(standup_reservation(), "FREQ=WEEKLY;COUNT=4", true)
+            .await
+            .unwrap();
+        assert_eq!(rsvps.len(), 3);
+        assert_eq!(conflicts.len(), 1);
+        assert!(rsvps.iter().all(|rsvp| rsvp.start != taken.start));
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn reserve_recurring_should_reject_invalid_rule(pool: PgPool) {
+        let manager = ReservationManager::new(pool);
+        let err = manager
+            .reserve_recurring(standup_reservation(), "FREQ=WEEKLY", false)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, abi::Error::InvalidRecurrenceRule(_)));
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn cancel_series_should_follow_scope(pool: PgPool) {
+        let manager = ReservationManager::new(pool);
+        let (rsvps, _) = manager
+            .reserve_recurring(standup_reservation(), "FREQ=WEEKLY;COUNT=4", false)
+            .await
+            .unwrap();
+
+        let err = manager
+            .cancel_series(
+                rsvps[0].id,
+                SeriesScope::All,
+                "shurid".into(),
+                Some(rsvps[0].version + 1),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, abi::Error::VersionMismatch { .. }));
+
+        let cancelled = manager
+            .cancel_series(rsvps[3].id, SeriesScope::Occurrence, "shurid".into(), None)
+            .await
+            .unwrap();
+        assert_eq!(cancelled.len(), 1);
+        assert_eq!(cancelled[0].id, rsvps[3].id);
+
+        // the last occurrence is already cancelled and skipped
+        let cancelled = manager
+            .cancel_series(rsvps[1].id, SeriesScope::Following, "shurid".into(), None)
+            .await
+            .unwrap();
+        let ids: Vec<_> = cancelled.iter().map(|rsvp| rsvp.id).collect();
+        assert_eq!(ids, vec![rsvps[1].id, rsvps[2].id]);
+
+        let first = manager.get(rsvps[0].id).await.unwrap();
+        assert_eq!(first.status, ReservationStatus::Pending as i32);
+
+        // the reservation itself is returned although it was already cancelled
+        let cancelled = manager
+            .cancel_series(rsvps[2].id, SeriesScope::All, "shurid".into(), None)
+            .await
+            .unwrap();
+        let ids: Vec<_> = cancelled.iter().map(|rsvp| rsvp.id).collect();
+        assert_eq!(ids, vec![rsvps[0].id, rsvps[2].id]);
+        assert!(
+            cancelled
+                .iter()
+                .all(|rsvp| rsvp.status == ReservationStatus::Cancelled as i32)
+        );
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn update_series_note_should_follow_scope(pool: PgPool) {
+        let manager = ReservationManager::new(pool);
+        let (rsvps, _) = manager
+            .reserve_recurring(standup_reservation(), "FREQ=DAILY;COUNT=3", false)
+            .await
+            .unwrap();
+
+        let updated = manager
+            .update_series_note(rsvps[0].id, "retro".into(), SeriesScope::All, None)
+            .await
+            .unwrap();
+        assert_eq!(updated.len(), 3);
+        assert!(updated.iter().all(|rsvp| rsvp.note == "retro"));
+
+        let updated = manager
+            .update_series_note(rsvps[1].id, "moved".into(), SeriesScope::Occurrence, None)
+            .await
+            .unwrap();
+        assert_eq!(updated.len(), 1);
+        let last = manager.get(rsvps[2].id).await.unwrap();
+        assert_eq!(last.note, "retro");
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn pooled_resource_should_admit_reservations_up_to_capacity(pool: PgPool) {
+        let manager = ReservationManager::new(pool);
+        let parking = Resource::new("parking-lot-a", "Parking lot A", "parking").with_capacity(2);
+        ResourceManager::from(&manager)
+            .create(parking)
+            .await
+            .unwrap();
+
+        // at most two of them overlap at any time
+        for (start, end) in [
+            ("2025-05-13T08:00:00Z", "2025-05-13T10:00:00Z"),
+            ("2025-05-13T11:00:00Z", "2025-05-13T13:00:00Z"),
+            ("2025-05-13T09:00:00Z", "2025-05-13T12:00:00Z"),
+        ] {
+            manager
+                .reserve(parking_reservation(start, end))
+                .await
+                .unwrap();
+        }
+
+        let err = manager
+            .reserve(parking_reservation(
+                "2025-05-13T09:30:00Z",
+                "2025-05-13T11:30:00Z",
+            ))
+            .await
+            .unwrap_err();
+        let info = ReservationConflictInfo::Parsed(ReservationConflict {
+            new: ReservationWindow {
+                resource_id: "parking-lot-a".to_string(),
+                start: "2025-05-13T09:30:00Z".parse().unwrap(),
+                end: "2025-05-13T11:30:00Z".parse().unwrap(),
+            },
+            old: ReservationWindow {
+                resource_id: "parking-lot-a".to_string(),
+                start: "2025-05-13T09:30:00Z".parse().unwrap(),
+                end: "2025-05-13T10:00:00Z".parse().unwrap(),
+            },
+        });
+        assert_eq!(err, abi::Error::ConflictReservation(info));
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn reschedule_should_move_reservation_in_place(pool: PgPool) {
+        let (rsvp, manager) = make_shur_reservation(pool).await;
+
+        // overlapping its own window is fine
+        let start: DateTime<Utc> = "2025-05-14T22:00:00Z".parse().unwrap();
+        let end: DateTime<Utc> = "2025-05-16T19:00:00Z".parse().unwrap();
+        let moved = manager
+            .reschedule(rsvp.id, start, end, None, None)
+            .await
+            .unwrap();
+        assert_eq!(moved.id, rsvp.id);
+        assert_eq!(moved.get_window(), (start, end));
+        assert_eq!(moved.resource_id, rsvp.resource_id);
+
+        let moved = manager
+            .reschedule(
+                rsvp.id,
+                start,
+                end,
+                Some("ocean-view-room-778".into()),
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(moved.resource_id, "ocean-view-room-778");
+        assert_eq!(manager.get(rsvp.id).await.unwrap(), moved);
+
+        let ops: Vec<String> = sqlx::query_scalar(
+            "SELECT op::text FROM rsvp.reservation_changes WHERE reservation_id = $1 ORDER BY id",
+        )
+        .bind(rsvp.id)
+        .fetch_all(&manager.pool)
+        .await
+        .unwrap();
+        assert_eq!(ops, vec!["create", "update", "update"]);
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn cancelled_reservation_should_not_be_moved(pool: PgPool) {
+        let (rsvp, manager) = make_shur_reservation(pool).await;
+        let other = manager
+            .reserve(shur_reservation_for("ocean-view-room-778"))
+            .await
+            .unwrap();
+        let rsvp = manager
+            .cancel(rsvp.id, "shurid".into(), None)
+            .await
+            .unwrap();
+        let cancelled = abi::Error::InvalidTransition {
+            current: ReservationStatus::Cancelled,
+            requested: ReservationStatus::Cancelled,
+        };
+
+        let (start, end) = rsvp.get_window();
+        let err = manager
+            .reschedule(rsvp.id, start, end, Some(other.resource_id.clone()), None)
+            .await
+            .unwrap_err();
+        assert_eq!(err, cancelled);
+        let err = manager
+            .swap(rsvp.id, other.id, false, None, None)
+            .await
+            .unwrap_err();
+        assert_eq!(err, cancelled);
+        assert_eq!(manager.get(rsvp.id).await.unwrap(), rsvp);
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn reschedule_into_taken_window_should_conflict(pool: PgPool) {
+        let (rsvp, manager) = make_shur_reservation(pool).await;
+        let taken = manager
+            .reserve(shur_reservation_for("ocean-view-room-778"))
+            .await
+            .unwrap();
+
+        let (start, end) = rsvp.get_window();
+        let err = manager
+            .reschedule(rsvp.id, start, end, Some(taken.resource_id.clone()), None)
+            .await
+            .unwrap_err();
+        let abi::Error::ConflictReservation(ReservationConflictInfo::Parsed(conflict)) = err else {
+            panic!("expected a parsed conflict, got {err:?}");
+        };
+        assert_eq!(conflict.new.resource_id, "ocean-view-room-778");
+        assert_eq!((conflict.old.start, conflict.old.end), taken.get_window());
+
+        // the reservation stays where it was
+        assert_eq!(manager.get(rsvp.id).await.unwrap(), rsvp);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn reschedule_on_pooled_resource_should_follow_capacity(pool: PgPool) {
+        let manager = ReservationManager::new(pool);
+        let parking = Resource::new("parking-lot-a", "Parking lot A", "parking").with_capacity(2);
+        ResourceManager::from(&manager)
+            .create(parking)
+            .await
+            .unwrap();
+        let mut ids = vec![];
+        for (start, end) in [
+            ("2025-05-13T08:00:00Z", "2025-05-13T10:00:00Z"),
+            ("2025-05-13T09:00:00Z", "2025-05-13T12:00:00Z"),
+            ("2025-05-13T13:00:00Z", "2025-05-13T14:00:00Z"),
+        ] {
+            let rsvp = manager
+                .reserve(parking_reservation(start, end))
+                .await
+                .unwrap();
+            ids.push(rsvp.id);
+        }
+
+        // still two at most, the moved reservation is not counted twice
+        manager
+            .reschedule(
+                ids[0],
+                "2025-05-13T08:00:00Z".parse().unwrap(),
+                "2025-05-13T11:00:00Z".parse().unwrap(),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        let err = manager
+            .reschedule(
+                ids[2],
+                "2025-05-13T10:00:00Z".parse().unwrap(),
+                "2025-05-13T10:30:00Z".parse().unwrap(),
+                None,
+                None,
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, abi::Error::ConflictReservation(_)));
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn swap_should_exchange_resources(pool: PgPool) {
+        let (a, manager) = make_shur_reservation(pool).await;
+        let b = manager
+            .reserve(shur_reservation_for("ocean-view-room-778"))
+            .await
+            .unwrap();
+
+        let (swapped_a, swapped_b) = manager.swap(a.id, b.id, false, None, None).await.unwrap();
+        assert_eq!(swapped_a.resource_id, b.resource_id);
+        assert_eq!(swapped_b.resource_id, a.resource_id);
+        assert_eq!(swapped_a.get_window(), a.get_window());
+        assert_eq!(manager.get(b.id).await.unwrap(), swapped_b);
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn swap_should_exchange_windows(pool: PgPool) {
+        let (a, manager) = make_shur_reservation(pool).await;
+        let b = manager
+            .reserve(Reservation::new_pending(
+                "aliceid",
+                "ocean-view-room-778",
+                "2025-05-20T15:00:00-0700".parse().unwrap(),
+                "2025-05-22T12:00:00-0700".parse().unwrap(),
+                "",
+            ))
+            .await
+            .unwrap();
+
+        let err = manager
+            .swap(a.id, b.id, true, Some(a.version), Some(b.version + 1))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, abi::Error::VersionMismatch { .. }));
+
+        let (swapped_a, swapped_b) = manager
+            .swap(a.id, b.id, true, Some(a.version), Some(b.version))
+            .await
+            .unwrap();
+        assert_eq!(swapped_a.resource_id, b.resource_id);
+        assert_eq!(swapped_a.get_window(), b.get_window());
+        assert_eq!(swapped_b.resource_id, a.resource_id);
+        assert_eq!(swapped_b.get_window(), a.get_window());
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn swap_into_taken_window_should_conflict(pool: PgPool) {
+        let (a, manager) = make_shur_reservation(pool).await;
+        let later = |resource_id| {
+            Reservation::new_pending(
+                "aliceid",
+                resource_id,
+                "2025-05-20T15:00:00-0700".parse().unwrap(),
+                "2025-05-22T12:00:00-0700".parse().unwrap(),
+                "",
+            )
+        };
+        let b = manager.reserve(later("ocean-view-room-778")).await.unwrap();
+        // takes the window b would have in a's room
+        manager.reserve(later("ocean-view-room-777")).await.unwrap();
+
+        let err = manager
+            .swap(a.id, b.id, false, None, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, abi::Error::ConflictReservation(_)));
+        assert_eq!(manager.get(a.id).await.unwrap(), a);
+        assert_eq!(manager.get(b.id).await.unwrap(), b);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn parallel_reservations_should_not_exceed_capacity(pool: PgPool) {
+        let manager = ReservationManager::new(pool);
+        let lot = Resource::new("parking-lot-a", "Parking lot A", "parking").with_capacity(3);
+        ResourceManager::from(&manager).create(lot).await.unwrap();
+
+        let tasks: Vec<_> = (0..10)
+            .map(|_| {
+                let manager = manager.clone();
+                tokio::spawn(async move {
+                    manager
+                        .reserve(parking_reservation(
+                            "2025-05-13T08:00:00Z",
+                            "2025-05-13T18:00:00Z",
+                        ))
+                        .await
+                })
+            })
+            .collect();
+
+        let mut reserved = 0;
+        for task in tasks {
+            match task.await.unwrap() {
+                Ok(_) => reserved += 1,
+                Err(e) => assert!(matches!(e, abi::Error::ConflictReservation(_))),
+            }
+        }
+        assert_eq!(reserved, 3);
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn availability_should_return_free_intervals(pool: PgPool) {
+        let (_, manager) = make_reservation(
+            pool.clone(),
+            "shurid",
+            "meeting-room-1",
+            "2025-05-13T10:00:00Z",
+            "2025-05-13T12:00:00Z",
+            "planning",
+        )
+        .await;
+        make_reservation(
+            pool,
+            "shurid",
+            "meeting-room-1",
+            "2025-05-13T12:30:00Z",
+            "2025-05-13T13:00:00Z",
+            "standup",
+        )
+        .await;
+
+        let query = AvailabilityQueryBuilder::default()
+            .resource_ids(vec![
+                "meeting-room-1".to_string(),
+                "projector-3".to_string(),
+            ])
+            .start("2025-05-13T09:00:00Z".parse::<Timestamp>().unwrap())
+            .end("2025-05-13T18:00:00Z".parse::<Timestamp>().unwrap())
+            .min_duration(prost_types::Duration {
+                seconds: 60 * 60,
+                nanos: 0,
+            })
+            .build()
+            .unwrap();
+        let intervals = manager.availability(query).await.unwrap();
+
+        // the half hour gap between the meetings is too short
+        let windows: Vec<_> = intervals
+            .iter()
+            .map(|i| (i.resource_id.as_str(), i.start.unwrap(), i.end.unwrap()))
+            .collect();
+        let at = |s: &str| s.parse::<Timestamp>().unwrap();
+        assert_eq!(
+            windows,
+            vec![
+                (
+                    "meeting-room-1",
+                    at("2025-05-13T09:00:00Z"),
+                    at("2025-05-13T10:00:00Z")
+                ),
+                (
+                    "meeting-room-1",
+                    at("2025-05-13T13:00:00Z"),
+                    at("2025-05-13T18:00:00Z")
+                ),
+                (
+                    "projector-3",
+                    at("2025-05-13T09:00:00Z"),
+                    at("2025-05-13T18:00:00Z")
+                ),
+            ]
+        );
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn availability_of_unknown_or_inactive_resource_should_reject(pool: PgPool) {
+        let manager = ReservationManager::new(pool);
+        ResourceManager::from(&manager)
+            .deactivate("projector-3")
+            .await
+            .unwrap();
+
+        for id in ["meeting-room-l", "projector-3"] {
+            let query = AvailabilityQueryBuilder::default()
+                .resource_ids(vec!["meeting-room-1".to_string(), id.to_string()])
+                .start("2025-05-13T09:00:00Z".parse::<Timestamp>().unwrap())
+                .end("2025-05-13T18:00:00Z".parse::<Timestamp>().unwrap())
+                .build()
+                .unwrap();
+            let err = manager.availability(query).await.unwrap_err();
+            assert_eq!(err, abi::Error::UnavailableResource(id.to_string()));
+        }
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn availability_of_pooled_resource_should_follow_capacity(pool: PgPool) {
+        let manager = ReservationManager::new(pool);
+        let lot = Resource::new("parking-lot-a", "Parking lot A", "parking").with_capacity(2);
+        ResourceManager::from(&manager).create(lot).await.unwrap();
+        for (start, end) in [
+            ("2025-05-13T09:00:00Z", "2025-05-13T12:00:00Z"),
+            ("2025-05-13T11:00:00Z", "2025-05-13T13:00:00Z"),
+        ] {
+            manager
+                .reserve(parking_reservation(start, end))
+                .await
+                .unwrap();
+        }
+
+        let query = AvailabilityQueryBuilder::default()
+            .resource_ids(vec!["parking-lot-a".to_string()])
+            .start("2025-05-13T08:00:00Z".parse::<Timestamp>().unwrap())
+            .end("2025-05-13T18:00:00Z".parse::<Timestamp>().unwrap())
+            .build()
+            .unwrap();
+        let intervals = manager.availability(query).await.unwrap();
+
+        // only full between 11:00 and 12:00
+        assert_eq!(intervals.len(), 2);
+        assert_eq!(
+            intervals[0].end,
+            Some("2025-05-13T11:00:00Z".parse().unwrap())
+        );
+        assert_eq!(
+            intervals[1].start,
+            Some("2025-05-13T12:00:00Z".parse().unwrap())
+        );
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn find_available_should_skip_taken_resources(pool: PgPool) {
+        let (_, manager) = make_shur_reservation(pool).await;
+
+        let query = FindAvailableQuery {
+            group: Some(ResourceGroup::of_resources([
+                "ocean-view-room-778",
+                "ocean-view-room-777",
+                "meeting-room-1",
+            ])),
+            start: Some("2025-05-14T15:00:00-0700".parse().unwrap()),
+            end: Some("2025-05-16T12:00:00-0700".parse().unwrap()),
+        };
+        let ids = manager.find_available(query.clone()).await.unwrap();
+        assert_eq!(ids, vec!["ocean-view-room-778", "meeting-room-1"]);
+
+        let query = FindAvailableQuery {
+            group: Some(ResourceGroup::of_kind("room")),
+            ..query
+        };
+        let ids = manager.find_available(query).await.unwrap();
+        assert_eq!(ids, vec!["meeting-room-1", "ocean-view-room-778"]);
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn reserve_any_should_book_each_free_resource_once(pool: PgPool) {
+        let manager = ReservationManager::new(pool);
+
+        let tasks: Vec<_> = (0..5)
+            .map(|_| {
+                let manager = manager.clone();
+                tokio::spawn(async move {
+                    manager
+                        .reserve_any(ResourceGroup::of_kind("room"), shur_reservation_for(""))
+                        .await
+                })
+            })
+            .collect();
+
+        let mut booked = vec![];
+        for task in tasks {
+            match task.await.unwrap() {
+                Ok(rsvp) => booked.push(rsvp.resource_id),
+                Err(e) => assert_eq!(e, abi::Error::NoAvailableResource),
+            }
+        }
+        booked.sort();
+        assert_eq!(
+            booked,
+            vec![
+                "meeting-room-1",
+                "ocean-view-room-777",
+                "ocean-view-room-778"
+            ]
+        );
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn query_reservations_should_work(pool: PgPool) {
+        let (rsvp, manager) = make_shur_reservation(pool).await;
+        let query = ReservationQueryBuilder::default()
+            .user_id("shurid")
             .start("2025-05-12T15:00:00-0700".parse::<Timestamp>().unwrap())
             .end("2025-05-15T12:00:00-0700".parse::<Timestamp>().unwrap())
             .status(ReservationStatus::Pending as i32)
             .build()
             .unwrap();
 
-        let rsvps = manager.query(query).await.unwrap();
+        let rsvps = manager.query(query).await.unwrap().reservations;
         assert_eq!(rsvps.len(), 1);
         assert_eq!(rsvps[0], rsvp);
 
@@ -222,10 +2014,440 @@ mod tests {
             .status(ReservationStatus::Pending as i32)
             .build()
             .unwrap();
-        let rsvps = manager.query(query).await.unwrap();
+        let rsvps = manager.query(query).await.unwrap().reservations;
         assert!(rsvps.is_empty());
     }
 
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn query_should_filter_by_any_or_several_statuses(pool: PgPool) {
+        let manager = ReservationManager::new(pool);
+        let stay = |resource_id| {
+            Reservation::new_pending(
+                "shurid",
+                resource_id,
+                "2025-05-13T15:00:00-0700".parse().unwrap(),
+                "2025-05-16T12:00:00-0700".parse().unwrap(),
+                "",
+            )
+        };
+        let pending = manager.reserve(stay("ocean-view-room-777")).await.unwrap();
+        let confirmed = manager.reserve(stay("ocean-view-room-778")).await.unwrap();
+        let confirmed = manager.change_status(confirmed.id, None).await.unwrap();
+        let cancelled = manager.reserve(stay("meeting-room-1")).await.unwrap();
+        let cancelled = manager
+            .cancel(cancelled.id, "shurid".into(), None)
+            .await
+            .unwrap();
+
+        let query = ReservationQueryBuilder::default()
+            .user_id("shurid")
+            .start("2025-05-12T00:00:00-0700".parse::<Timestamp>().unwrap())
+            .end("2025-05-17T00:00:00-0700".parse::<Timestamp>().unwrap())
+            .build()
+            .unwrap();
+        let rsvps = manager.query(query.clone()).await.unwrap().reservations;
+        assert_eq!(rsvps, vec![pending.clone(), confirmed.clone(), cancelled]);
+
+        let query = ReservationQuery {
+            statuses: vec![
+                ReservationStatus::Pending as i32,
+                ReservationStatus::Confirmed as i32,
+            ],
+            ..query
+        };
+        let rsvps = manager.query(query).await.unwrap().reservations;
+        assert_eq!(rsvps, vec![pending, confirmed]);
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn query_should_filter_by_several_resources_and_users(pool: PgPool) {
+        let manager = ReservationManager::new(pool);
+        let stay = |user_id, resource_id| {
+            Reservation::new_pending(
+                user_id,
+                resource_id,
+                "2025-05-13T15:00:00-0700".parse().unwrap(),
+                "2025-05-16T12:00:00-0700".parse().unwrap(),
+                "",
+            )
+        };
+        let first = manager
+            .reserve(stay("shurid", "ocean-view-room-777"))
+            .await
+            .unwrap();
+        let second = manager
+            .reserve(stay("aliceid", "ocean-view-room-778"))
+            .await
+            .unwrap();
+        manager
+            .reserve(stay("bobid", "meeting-room-1"))
+            .await
+            .unwrap();
+
+        let query = ReservationQueryBuilder::default()
+            .resource_ids(vec![
+                "ocean-view-room-777".to_string(),
+                "ocean-view-room-778".to_string(),
+            ])
+            .start("2025-05-12T00:00:00-0700".parse::<Timestamp>().unwrap())
+            .end("2025-05-17T00:00:00-0700".parse::<Timestamp>().unwrap())
+            .build()
+            .unwrap();
+        let rsvps = manager.query(query.clone()).await.unwrap().reservations;
+        assert_eq!(rsvps, vec![first.clone(), second]);
+
+        let query = ReservationQuery {
+            user_ids: vec!["shurid".to_string(), "bobid".to_string()],
+            ..query
+        };
+        let rsvps = manager.query(query).await.unwrap().reservations;
+        assert_eq!(rsvps, vec![first]);
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn query_should_search_notes(pool: PgPool) {
+        let manager = ReservationManager::new(pool);
+        let stay = |resource_id, note| {
+            Reservation::new_pending(
+                "shurid",
+                resource_id,
+                "2025-05-13T15:00:00-0700".parse().unwrap(),
+                "2025-05-16T12:00:00-0700".parse().unwrap(),
+                note,
+            )
+        };
+        let crib = manager
+            .reserve(stay(
+                "ocean-view-room-777",
+                "VIP, late check-in, needs crib",
+            ))
+            .await
+            .unwrap();
+        manager
+            .reserve(stay("ocean-view-room-778", "VIP, early check-in"))
+            .await
+            .unwrap();
+
+        let query = ReservationQueryBuilder::default()
+            .start("2025-05-12T00:00:00-0700".parse::<Timestamp>().unwrap())
+            .end("2025-05-17T00:00:00-0700".parse::<Timestamp>().unwrap())
+            .note_search("cribs")
+            .build()
+            .unwrap();
+        let rsvps = manager.query(query.clone()).await.unwrap().reservations;
+        assert_eq!(rsvps, vec![crib]);
+
+        let query = ReservationQuery {
+            note_search: "vip -late".to_string(),
+            ..query
+        };
+        let rsvps = manager.query(query).await.unwrap().reservations;
+        assert_eq!(rsvps.len(), 1);
+        assert_eq!(rsvps[0].resource_id, "ocean-view-room-778");
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn attributes_should_be_patched_and_queried(pool: PgPool) {
+        let manager = ReservationManager::new(pool);
+        let stay = |resource_id| {
+            Reservation::new_pending(
+                "shurid",
+                resource_id,
+                "2025-05-13T15:00:00-0700".parse().unwrap(),
+                "2025-05-16T12:00:00-0700".parse().unwrap(),
+                "",
+            )
+            .with_attribute("source", "web")
+        };
+        let rsvp = manager
+            .reserve(stay("ocean-view-room-777").with_attribute("crib", "yes"))
+            .await
+            .unwrap();
+        manager.reserve(stay("ocean-view-room-778")).await.unwrap();
+        assert_eq!(manager.get(rsvp.id).await.unwrap(), rsvp);
+
+        let rsvp = manager
+            .update_attributes(
+                rsvp.id,
+                HashMap::from([("guests".to_string(), "2".to_string())]),
+                vec!["source".to_string()],
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            rsvp.attributes,
+            HashMap::from([
+                ("crib".to_string(), "yes".to_string()),
+                ("guests".to_string(), "2".to_string()),
+            ])
+        );
+
+        let query = ReservationQueryBuilder::default()
+            .start("2025-05-12T00:00:00-0700".parse::<Timestamp>().unwrap())
+            .end("2025-05-17T00:00:00-0700".parse::<Timestamp>().unwrap())
+            .attributes(HashMap::from([("crib".to_string(), "yes".to_string())]))
+            .build()
+            .unwrap();
+        let rsvps = manager.query(query.clone()).await.unwrap().reservations;
+        assert_eq!(rsvps, vec![rsvp]);
+
+        let query = ReservationQuery {
+            attributes: HashMap::from([("source".to_string(), "web".to_string())]),
+            ..query
+        };
+        let rsvps = manager.query(query).await.unwrap().reservations;
+        assert_eq!(rsvps.len(), 1);
+        assert_eq!(rsvps[0].resource_id, "ocean-view-room-778");
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn query_should_honour_match_mode(pool: PgPool) {
+        // a Tuesday to Thursday stay
+        let (rsvp, manager) = make_shur_reservation(pool).await;
+
+        // Wednesday
+        let query = |mode: QueryMatchMode| {
+            ReservationQueryBuilder::default()
+                .resource_id("ocean-view-room-777")
+                .start("2025-05-14T00:00:00-0700".parse::<Timestamp>().unwrap())
+                .end("2025-05-15T00:00:00-0700".parse::<Timestamp>().unwrap())
+                .status(ReservationStatus::Pending as i32)
+                .mode(mode as i32)
+                .build()
+                .unwrap()
+        };
+
+        let rsvps = manager
+            .query(query(QueryMatchMode::Contained))
+            .await
+            .unwrap();
+        assert!(rsvps.reservations.is_empty());
+        let rsvps = manager
+            .query(query(QueryMatchMode::Overlaps))
+            .await
+            .unwrap();
+        assert_eq!(rsvps.reservations, vec![rsvp.clone()]);
+        let rsvps = manager
+            .query(query(QueryMatchMode::StartsWithin))
+            .await
+            .unwrap();
+        assert!(rsvps.reservations.is_empty());
+
+        // Tuesday
+        let mut tuesday = query(QueryMatchMode::StartsWithin);
+        tuesday.start = Some("2025-05-13T00:00:00-0700".parse().unwrap());
+        tuesday.end = Some("2025-05-14T00:00:00-0700".parse().unwrap());
+        let rsvps = manager.query(tuesday).await.unwrap();
+        assert_eq!(rsvps.reservations, vec![rsvp]);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn query_pages_should_follow_cursor(pool: PgPool) {
+        let manager = ReservationManager::new(pool);
+        let lot = Resource::new("parking-lot-a", "Parking lot A", "parking").with_capacity(30);
+        ResourceManager::from(&manager).create(lot).await.unwrap();
+        // same window for all, so pages are told apart by id
+        let reserve = || {
+            manager.reserve(parking_reservation(
+                "2025-05-13T08:00:00Z",
+                "2025-05-13T18:00:00Z",
+            ))
+        };
+        let mut ids = vec![];
+        for _ in 0..25 {
+            ids.push(reserve().await.unwrap().id);
+        }
+
+        let mut query = ReservationQueryBuilder::default()
+            .resource_id("parking-lot-a")
+            .start("2025-05-13T00:00:00Z".parse::<Timestamp>().unwrap())
+            .end("2025-05-14T00:00:00Z".parse::<Timestamp>().unwrap())
+            .status(ReservationStatus::Pending as i32)
+            .page_size(10)
+            .build()
+            .unwrap();
+        let mut pages = vec![];
+        loop {
+            let page = manager.query(query.clone()).await.unwrap();
+            pages.push(page.reservations.len());
+            query.cursor = page.next_cursor;
+            if pages.len() == 1 {
+                // inserted between page fetches, it is neither skipped nor duplicated
+                ids.push(reserve().await.unwrap().id);
+            }
+            let walked: Vec<_> = page.reservations.iter().map(|rsvp| rsvp.id).collect();
+            ids.retain(|id| !walked.contains(id));
+            if query.cursor.is_empty() {
+                break;
+            }
+        }
+
+        assert_eq!(pages, vec![10, 10, 6]);
+        assert!(ids.is_empty());
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn query_pages_should_have_requested_size(pool: PgPool) {
+        let manager = ReservationManager::new(pool);
+        let lot = Resource::new("parking-lot-a", "Parking lot A", "parking").with_capacity(10);
+        ResourceManager::from(&manager).create(lot).await.unwrap();
+        for _ in 0..6 {
+            manager
+                .reserve(parking_reservation(
+                    "2025-05-13T08:00:00Z",
+                    "2025-05-13T18:00:00Z",
+                ))
+                .await
+                .unwrap();
+        }
+
+        let query = ReservationQueryBuilder::default()
+            .resource_id("parking-lot-a")
+            .start("2025-05-13T00:00:00Z".parse::<Timestamp>().unwrap())
+            .end("2025-05-14T00:00:00Z".parse::<Timestamp>().unwrap())
+            .page(2)
+            .page_size(4)
+            .build()
+            .unwrap();
+        let page = manager.query(query.clone()).await.unwrap();
+        assert_eq!(page.reservations.len(), 2);
+
+        let query = ReservationQuery {
+            page_size: 500,
+            ..query
+        };
+        let err = manager.query(query).await.unwrap_err();
+        assert_eq!(err, abi::Error::InvalidPageSize(500));
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn query_with_malformed_cursor_should_reject(pool: PgPool) {
+        let manager = ReservationManager::new(pool);
+        let query = ReservationQueryBuilder::default()
+            .start("2025-05-13T00:00:00Z".parse::<Timestamp>().unwrap())
+            .end("2025-05-14T00:00:00Z".parse::<Timestamp>().unwrap())
+            .cursor("garbage")
+            .build()
+            .unwrap();
+        let err = manager.query(query).await.unwrap_err();
+        assert_eq!(err, abi::Error::InvalidCursor("garbage".to_string()));
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn query_stream_should_yield_reservations(pool: PgPool) {
+        let (rsvp, manager) = make_shur_reservation(pool.clone()).await;
+        make_reservation(
+            pool,
+            "shurid",
+            "ocean-view-room-778",
+            "2025-05-13T15:00:00-0700",
+            "2025-05-14T12:00:00-0700",
+            "another reservation",
+        )
+        .await;
+
+        let query = ReservationQueryBuilder::default()
+            .user_id("shurid")
+            .start("2025-05-12T15:00:00-0700".parse::<Timestamp>().unwrap())
+            .end("2025-05-16T12:00:00-0700".parse::<Timestamp>().unwrap())
+            .status(ReservationStatus::Pending as i32)
+            .build()
+            .unwrap();
+
+        let rsvps: Vec<_> = manager.query_stream(query).await.unwrap().collect().await;
+        assert_eq!(rsvps.len(), 2);
+        assert_eq!(rsvps[0].as_ref().unwrap(), &rsvp);
+        assert_eq!(
+            rsvps[1].as_ref().unwrap().resource_id,
+            "ocean-view-room-778"
+        );
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn query_stream_should_not_be_paged(pool: PgPool) {
+        let manager = ReservationManager::new(pool);
+        let lot = Resource::new("parking-lot-a", "Parking lot A", "parking").with_capacity(150);
+        ResourceManager::from(&manager).create(lot).await.unwrap();
+        let rsvps = (0..150)
+            .map(|_| parking_reservation("2025-05-13T08:00:00Z", "2025-05-13T18:00:00Z"))
+            .collect();
+        manager.reserve_many(rsvps).await.unwrap();
+
+        let query = ReservationQueryBuilder::default()
+            .resource_id("parking-lot-a")
+            .start("2025-05-13T00:00:00Z".parse::<Timestamp>().unwrap())
+            .end("2025-05-14T00:00:00Z".parse::<Timestamp>().unwrap())
+            .page_size(100)
+            .build()
+            .unwrap();
+        let rsvps: Vec<_> = manager.query_stream(query).await.unwrap().collect().await;
+        assert_eq!(rsvps.len(), 150);
+        assert!(rsvps.iter().all(|rsvp| rsvp.is_ok()));
+    }
+
+    fn shur_reservation_for(resource_id: &str) -> Reservation {
+        Reservation::new_pending(
+            "shurid",
+            resource_id,
+            "2025-05-13T15:00:00-0700".parse().unwrap(),
+            "2025-05-15T12:00:00-0700".parse().unwrap(),
+            "this is shur's reservation",
+        )
+    }
+
+    fn parking_reservation(start: &str, end: &str) -> Reservation {
+        Reservation::new_pending(
+            "shurid",
+            "parking-lot-a",
+            start.parse().unwrap(),
+            end.parse().unwrap(),
+            "parking",
+        )
+    }
+
+    /// Tuesday 10:00 to 10:30 UTC
+    fn standup_reservation() -> Reservation {
+        Reservation::new_pending(
+            "shurid",
+            "meeting-room-1",
+            "2025-06-03T10:00:00Z".parse().unwrap(),
+            "2025-06-03T10:30:00Z".parse().unwrap(),
+            "standup",
+        )
+    }
+
+    fn maintenance_block() -> Reservation {
+        Reservation::new_pending(
+            "admin",
+            "ocean-view-room-777",
+            "2025-05-14T08:00:00-0700".parse().unwrap(),
+            "2025-05-14T12:00:00-0700".parse().unwrap(),
+            "cleaning",
+        )
+    }
+
     async fn make_shur_reservation(pool: PgPool) -> (Reservation, ReservationManager) {
         make_reservation(
             pool,
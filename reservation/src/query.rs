@@ -0,0 +1,120 @@
+use abi::{QueryMatchMode, ReservationQuery};
+use sqlx::{Postgres, QueryBuilder, types::Json};
+
+/// Build the statement of a page of a validated reservation query. Every value is bound as
+/// a parameter, only the keywords depend on the query.
+pub(crate) fn build_query(query: &ReservationQuery) -> QueryBuilder<'static, Postgres> {
+    let mut builder = build_stream_query(query);
+    let page_size = query.get_page_size() as i64;
+    // pages are ignored when following a cursor
+    let offset = match query.get_cursor() {
+        Ok(Some(_)) => 0,
+        _ => (query.get_page() as i64 - 1) * page_size,
+    };
+    builder
+        .push(" LIMIT ")
+        .push_bind(page_size)
+        .push(" OFFSET ")
+        .push_bind(offset);
+    builder
+}
+
+/// Build the statement of every reservation matching a validated query, in page order but
+/// without paging, for results read row by row.
+pub(crate) fn build_stream_query(query: &ReservationQuery) -> QueryBuilder<'static, Postgres> {
+    let mut builder = QueryBuilder::new("SELECT * FROM rsvp.reservations WHERE ");
+
+    let timespan = query.get_timespan();
+    match QueryMatchMode::try_from(query.mode).unwrap_or(QueryMatchMode::Contained) {
+        QueryMatchMode::Contained => builder.push_bind(timespan).push(" @> timespan"),
+        QueryMatchMode::Overlaps => builder.push_bind(timespan).push(" && timespan"),
+        QueryMatchMode::StartsWithin => builder.push_bind(timespan).push(" @> lower(timespan)"),
+    };
+
+    let statuses: Vec<_> = query
+        .get_statuses()
+        .iter()
+        .map(|status| status.to_string())
+        .collect();
+    if !statuses.is_empty() {
+        builder
+            .push(" AND status = ANY(")
+            .push_bind(statuses)
+            .push("::rsvp.reservation_status[])");
+    }
+    let user_ids = query.get_user_ids();
+    if !user_ids.is_empty() {
+        builder
+            .push(" AND user_id = ANY(")
+            .push_bind(user_ids)
+            .push(")");
+    }
+    let resource_ids = query.get_resource_ids();
+    if !resource_ids.is_empty() {
+        builder
+            .push(" AND resource_id = ANY(")
+            .push_bind(resource_ids)
+            .push(")");
+    }
+    if !query.note_search.trim().is_empty() {
+        builder
+            .push(" AND note_tsv @@ websearch_to_tsquery('english', ")
+            .push_bind(query.note_search.clone())
+            .push(")");
+    }
+    if !query.attributes.is_empty() {
+        builder
+            .push(" AND attributes @> ")
+            .push_bind(Json(query.attributes.clone()));
+    }
+
+    let order = if query.desc { "DESC" } else { "ASC" };
+    // the cursor has been validated with the query
+    let cursor = query.get_cursor().ok().flatten();
+    if let Some(cursor) = cursor {
+        let op = if query.desc { "<" } else { ">" };
+        builder
+            .push(format_args!(" AND (lower(timespan), id) {op} ("))
+            .push_bind(cursor.start)
+            .push(", ")
+            .push_bind(cursor.id)
+            .push(")");
+    }
+
+    builder.push(format_args!(
+        " ORDER BY lower(timespan) {order}, id {order}"
+    ));
+    builder
+}
+
+#[cfg(test)]
+mod tests {
+    use abi::{ReservationQueryBuilder, ReservationStatus};
+    use prost_types::Timestamp;
+
+    use super::*;
+
+    #[test]
+    fn query_values_should_be_bound() {
+        let query = ReservationQueryBuilder::default()
+            .user_id("shurid'; DROP TABLE rsvp.reservations; --")
+            .start("2025-05-12T15:00:00-0700".parse::<Timestamp>().unwrap())
+            .end("2025-05-15T12:00:00-0700".parse::<Timestamp>().unwrap())
+            .statuses(vec![
+                ReservationStatus::Pending as i32,
+                ReservationStatus::Confirmed as i32,
+            ])
+            .mode(QueryMatchMode::Overlaps as i32)
+            .desc(true)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            build_query(&query).sql(),
+            "SELECT * FROM rsvp.reservations WHERE $1 && timespan \
+             AND status = ANY($2::rsvp.reservation_status[]) AND user_id = ANY($3) \
+             ORDER BY lower(timespan) DESC, id DESC LIMIT $4 OFFSET $5"
+        );
+        assert!(!build_stream_query(&query).sql().contains("LIMIT"));
+    }
+}
@@ -0,0 +1,204 @@
+use abi::Validator;
+use async_trait::async_trait;
+use sqlx::types::Json;
+
+use crate::{Catalog, ReservationManager, ResourceManager};
+
+#[async_trait]
+impl Catalog for ResourceManager {
+    async fn create(&self, resource: abi::Resource) -> Result<abi::Resource, abi::Error> {
+        resource.validate()?;
+        let created = sqlx::query_as(
+            "INSERT INTO rsvp.resources (id, name, kind, attributes, capacity)
+             VALUES ($1, $2, $3, $4, $5)
+             ON CONFLICT (id) DO NOTHING
+             RETURNING *",
+        )
+        .bind(&resource.id)
+        .bind(&resource.name)
+        .bind(&resource.kind)
+        .bind(Json(&resource.attributes))
+        .bind(resource.get_capacity())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        created.ok_or(abi::Error::DuplicateResource(resource.id))
+    }
+
+    async fn get(&self, id: &str) -> Result<abi::Resource, abi::Error> {
+        let resource = sqlx::query_as("SELECT * FROM rsvp.resources WHERE id = $1")
+            .bind(id)
+            .fetch_one(&self.pool)
+            .await?;
+        Ok(resource)
+    }
+
+    async fn update(&self, resource: abi::Resource) -> Result<abi::Resource, abi::Error> {
+        resource.validate()?;
+        let resource = sqlx::query_as(
+            "UPDATE rsvp.resources
+             SET name = $2, kind = $3, attributes = $4, capacity = $5, updated_at = now()
+             WHERE id = $1
+             RETURNING *",
+        )
+        .bind(&resource.id)
+        .bind(&resource.name)
+        .bind(&resource.kind)
+        .bind(Json(&resource.attributes))
+        .bind(resource.get_capacity())
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(resource)
+    }
+
+    async fn list(
+        &self,
+        kind: Option<String>,
+        include_inactive: bool,
+    ) -> Result<Vec<abi::Resource>, abi::Error> {
+        let resources = sqlx::query_as(
+            "SELECT * FROM rsvp.resources
+             WHERE ($1::varchar IS NULL OR kind = $1) AND (active OR $2)
+             ORDER BY id",
+        )
+        .bind(kind)
+        .bind(include_inactive)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(resources)
+    }
+
+    async fn deactivate(&self, id: &str) -> Result<abi::Resource, abi::Error> {
+        let resource = sqlx::query_as(
+            "UPDATE rsvp.resources SET active = FALSE, updated_at = now()
+             WHERE id = $1
+             RETURNING *",
+        )
+        .bind(id)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(resource)
+    }
+}
+
+impl ResourceManager {
+    pub fn new(pool: sqlx::PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// share the connection pool of the reservation manager
+impl From<&ReservationManager> for ResourceManager {
+    fn from(manager: &ReservationManager) -> Self {
+        Self::new(manager.pool.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use abi::Reservation;
+    use sqlx::PgPool;
+
+    use super::*;
+    use crate::Rsvp;
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn resource_crud_should_work(pool: PgPool) {
+        let resources = ResourceManager::new(pool);
+        let mut spot = abi::Resource::new("parking-spot-42", "Parking spot 42", "parking");
+        spot.attributes.insert("level".into(), "B2".into());
+
+        let created = resources.create(spot.clone()).await.unwrap();
+        assert_eq!(created, spot);
+        assert_eq!(resources.get("parking-spot-42").await.unwrap(), spot);
+
+        spot.name = "Parking spot 42 (EV)".into();
+        spot.attributes.insert("charger".into(), "yes".into());
+        let updated = resources.update(spot.clone()).await.unwrap();
+        assert_eq!(updated, spot);
+
+        let room = abi::Resource::new("ocean-view-room-777", "Ocean view room", "room");
+        resources.create(room.clone()).await.unwrap();
+        let parking = resources.list(Some("parking".into()), false).await.unwrap();
+        assert_eq!(parking, vec![spot]);
+
+        let deactivated = resources.deactivate("ocean-view-room-777").await.unwrap();
+        assert!(!deactivated.active);
+        assert_eq!(resources.list(None, false).await.unwrap().len(), 1);
+        assert_eq!(resources.list(None, true).await.unwrap().len(), 2);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn duplicate_resource_should_be_rejected(pool: PgPool) {
+        let resources = ResourceManager::new(pool);
+        let spot = abi::Resource::new("parking-spot-42", "Parking spot 42", "parking");
+        resources.create(spot.clone()).await.unwrap();
+
+        let err = resources.create(spot).await.unwrap_err();
+        assert_eq!(
+            err,
+            abi::Error::DuplicateResource("parking-spot-42".to_string())
+        );
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn missing_resource_should_return_not_found(pool: PgPool) {
+        let resources = ResourceManager::new(pool);
+        assert_eq!(
+            resources.get("nowhere").await.unwrap_err(),
+            abi::Error::NotFound
+        );
+        assert_eq!(
+            resources.deactivate("nowhere").await.unwrap_err(),
+            abi::Error::NotFound
+        );
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn reserve_unknown_or_inactive_resource_should_reject(pool: PgPool) {
+        let manager = ReservationManager::new(pool);
+        let resources = ResourceManager::from(&manager);
+
+        let err = manager
+            .reserve(parking_reservation("parking-spot-41"))
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err,
+            abi::Error::UnavailableResource("parking-spot-41".to_string())
+        );
+
+        let spot = abi::Resource::new("parking-spot-42", "Parking spot 42", "parking");
+        resources.create(spot).await.unwrap();
+        let rsvp = manager
+            .reserve(parking_reservation("parking-spot-42"))
+            .await
+            .unwrap();
+
+        // existing reservations are kept when the resource is deactivated
+        resources.deactivate("parking-spot-42").await.unwrap();
+        assert_eq!(manager.get(rsvp.id).await.unwrap(), rsvp);
+        manager
+            .cancel(rsvp.id, "shurid".into(), None)
+            .await
+            .unwrap();
+        let err = manager
+            .reserve(parking_reservation("parking-spot-42"))
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err,
+            abi::Error::UnavailableResource("parking-spot-42".to_string())
+        );
+    }
+
+    fn parking_reservation(resource_id: &str) -> Reservation {
+        Reservation::new_pending(
+            "shurid",
+            resource_id,
+            "2025-05-13T15:00:00-0700".parse().unwrap(),
+            "2025-05-15T12:00:00-0700".parse().unwrap(),
+            "parking",
+        )
+    }
+}
@@ -0,0 +1,37 @@
+mod reaper;
+mod service;
+
+use std::{pin::Pin, time::Duration};
+
+use abi::{Config, reservation_service_server::ReservationServiceServer};
+use futures::Stream;
+use reservation::{ChangeFeed, ReservationManager, ResourceManager};
+use tonic::{Status, transport::Server};
+
+pub type TonicStream<T> = Pin<Box<dyn Stream<Item = Result<T, Status>> + Send>>;
+pub type ReservationStream = TonicStream<abi::Reservation>;
+pub type SubscribeStream = TonicStream<abi::SubscribeResponse>;
+
+#[derive(Debug, Clone)]
+pub struct RsvpService {
+    manager: ReservationManager,
+    resources: ResourceManager,
+    feed: ChangeFeed,
+}
+
+/// start the gRPC server with the given configuration
+pub async fn start_server(config: &Config) -> Result<(), anyhow::Error> {
+    let addr = config.server.url().parse()?;
+    let svc = RsvpService::from_config(config).await?;
+    reaper::spawn_reaper(
+        svc.manager.clone(),
+        Duration::from_secs(config.server.expire_interval),
+    );
+
+    tracing::info!("listening on {addr}");
+    Server::builder()
+        .add_service(ReservationServiceServer::new(svc))
+        .serve(addr)
+        .await?;
+    Ok(())
+}
@@ -1,13 +1,14 @@
-pub fn divide_array(mut nums: Vec<i32>, k: i32) -> Vec<Vec<i32>> {
-    nums.sort();
-    let n = nums.len();
-    let res: Vec<Vec<i32>> = nums
-        .chunks(3)
-        .filter(|chunk| chunk[2] - chunk[0] <= k)
-        .map(|chunk| chunk.to_vec())
-        .collect();
-    if res.len() == n / 3 { res } else { vec![] }
-}
-fn main() {
-    println!("Hello, world!");
+use std::env;
+
+use abi::Config;
+
+#[tokio::main]
+async fn main() -> Result<(), anyhow::Error> {
+    tracing_subscriber::fmt::init();
+
+    // config file can be overridden by the RESERVATION_CONFIG env var
+    let filename = env::var("RESERVATION_CONFIG").unwrap_or_else(|_| "reservation.yml".into());
+    let config = Config::load(&filename)?;
+
+    service::start_server(&config).await
 }
@@ -0,0 +1,18 @@
+use std::time::Duration;
+
+use reservation::{ReservationManager, Rsvp};
+use tokio::task::JoinHandle;
+
+/// periodically cancel the pending reservations whose hold has expired. Every cancellation
+/// is recorded as a change by the database trigger, so subscribers are notified as well
+pub fn spawn_reaper(manager: ReservationManager, interval: Duration) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            if let Err(e) = manager.expire_pending().await {
+                tracing::warn!("failed to expire pending reservations: {e}");
+            }
+        }
+    })
+}
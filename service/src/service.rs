@@ -0,0 +1,728 @@
+use abi::{
+    AvailabilityRequest, AvailabilityResponse, BlockRequest, BlockResponse, CancelRequest,
+    CancelResponse, Config, ConfirmRequest, ConfirmResponse, CreateResourceRequest,
+    CreateResourceResponse, DeactivateResourceRequest, DeactivateResourceResponse,
+    FindAvailableRequest, FindAvailableResponse, GetRequest, GetResourceRequest,
+    GetResourceResponse, GetResponse, ListResourcesRequest, ListResourcesResponse, QueryRequest,
+    RescheduleRequest, RescheduleResponse, ReservationPage, ReserveAnyRequest, ReserveAnyResponse,
+    ReserveManyRequest, ReserveManyResponse, ReserveRecurringRequest, ReserveRecurringResponse,
+    ReserveRequest, ReserveResponse, SeriesScope, SubscribeRequest, SwapRequest, SwapResponse,
+    UpdateAttributesRequest, UpdateAttributesResponse, UpdateRequest, UpdateResourceRequest,
+    UpdateResourceResponse, UpdateResponse, convert_to_utc_time,
+    reservation_service_server::ReservationService,
+};
+use futures::TryStreamExt;
+use reservation::{Catalog, ChangeFeed, ReservationManager, ResourceManager, Rsvp};
+use tonic::{Request, Response, Status};
+
+use crate::{ReservationStream, RsvpService, SubscribeStream};
+
+impl RsvpService {
+    pub async fn new(manager: ReservationManager) -> Result<Self, abi::Error> {
+        let feed = ChangeFeed::start(&manager).await?;
+        let resources = ResourceManager::from(&manager);
+        Ok(Self {
+            manager,
+            resources,
+            feed,
+        })
+    }
+
+    pub async fn from_config(config: &Config) -> Result<Self, abi::Error> {
+        Self::new(ReservationManager::from_config(&config.db).await?).await
+    }
+}
+
+#[tonic::async_trait]
+impl ReservationService for RsvpService {
+    /// make a reservation
+    async fn reserve(
+        &self,
+        request: Request<ReserveRequest>,
+    ) -> Result<Response<ReserveResponse>, Status> {
+        let request = request.into_inner();
+        let Some(reservation) = request.reservation else {
+            return Err(Status::invalid_argument("missing reservation"));
+        };
+        let reservation = reservation.with_hold_ttl(request.hold_ttl)?;
+        let reservation = self.manager.reserve(reservation).await?;
+        Ok(Response::new(ReserveResponse {
+            reservation: Some(reservation),
+        }))
+    }
+
+    /// make several reservations at once, all or nothing
+    async fn reserve_many(
+        &self,
+        request: Request<ReserveManyRequest>,
+    ) -> Result<Response<ReserveManyResponse>, Status> {
+        let request = request.into_inner();
+        if request.reservations.is_empty() {
+            return Err(Status::invalid_argument("missing reservations"));
+        }
+        let reservations = request
+            .reservations
+            .into_iter()
+            .map(|rsvp| rsvp.with_hold_ttl(request.hold_ttl))
+            .collect::<Result<_, _>>()?;
+        let reservations = self.manager.reserve_many(reservations).await?;
+        Ok(Response::new(ReserveManyResponse { reservations }))
+    }
+
+    /// reserve every occurrence of a recurring reservation
+    async fn reserve_recurring(
+        &self,
+        request: Request<ReserveRecurringRequest>,
+    ) -> Result<Response<ReserveRecurringResponse>, Status> {
+        let request = request.into_inner();
+        let Some(reservation) = request.reservation else {
+            return Err(Status::invalid_argument("missing reservation"));
+        };
+        let reservation = reservation.with_hold_ttl(request.hold_ttl)?;
+        let (reservations, conflicts) = self
+            .manager
+            .reserve_recurring(reservation, &request.rrule, request.allow_partial)
+            .await?;
+        Ok(Response::new(ReserveRecurringResponse {
+            reservations,
+            conflicts,
+        }))
+    }
+
+    /// block a resource for a time window
+    async fn block(
+        &self,
+        request: Request<BlockRequest>,
+    ) -> Result<Response<BlockResponse>, Status> {
+        let request = request.into_inner();
+        let Some(reservation) = request.reservation else {
+            return Err(Status::invalid_argument("missing reservation"));
+        };
+        let (reservation, evicted) = self
+            .manager
+            .block(reservation, request.evict_pending)
+            .await?;
+        Ok(Response::new(BlockResponse {
+            reservation: Some(reservation),
+            evicted,
+        }))
+    }
+
+    /// confirm a pending reservation
+    async fn confirm(
+        &self,
+        request: Request<ConfirmRequest>,
+    ) -> Result<Response<ConfirmResponse>, Status> {
+        let request = request.into_inner();
+        let reservation = self
+            .manager
+            .change_status(request.id, expected_version(request.expected_version))
+            .await?;
+        Ok(Response::new(ConfirmResponse {
+            reservation: Some(reservation),
+        }))
+    }
+
+    /// update the note of a reservation
+    async fn update(
+        &self,
+        request: Request<UpdateRequest>,
+    ) -> Result<Response<UpdateResponse>, Status> {
+        let request = request.into_inner();
+        let scope = SeriesScope::try_from(request.scope).unwrap_or(SeriesScope::Occurrence);
+        let reservations = self
+            .manager
+            .update_series_note(
+                request.id,
+                request.note,
+                scope,
+                expected_version(request.expected_version),
+            )
+            .await?;
+        Ok(Response::new(UpdateResponse {
+            reservation: reservations.iter().find(|r| r.id == request.id).cloned(),
+            reservations,
+        }))
+    }
+
+    /// move a reservation to another window or resource
+    async fn reschedule(
+        &self,
+        request: Request<RescheduleRequest>,
+    ) -> Result<Response<RescheduleResponse>, Status> {
+        let request = request.into_inner();
+        let (Some(start), Some(end)) = (request.start, request.end) else {
+            return Err(abi::Error::InvalidTime.into());
+        };
+        let resource_id = (!request.resource_id.is_empty()).then_some(request.resource_id);
+        let reservation = self
+            .manager
+            .reschedule(
+                request.id,
+                convert_to_utc_time(start),
+                convert_to_utc_time(end),
+                resource_id,
+                expected_version(request.expected_version),
+            )
+            .await?;
+        Ok(Response::new(RescheduleResponse {
+            reservation: Some(reservation),
+        }))
+    }
+
+    /// exchange the resources of two reservations
+    async fn swap(&self, request: Request<SwapRequest>) -> Result<Response<SwapResponse>, Status> {
+        let request = request.into_inner();
+        let (a, b) = self
+            .manager
+            .swap(
+                request.id_a,
+                request.id_b,
+                request.swap_windows,
+                expected_version(request.expected_version_a),
+                expected_version(request.expected_version_b),
+            )
+            .await?;
+        Ok(Response::new(SwapResponse {
+            reservation_a: Some(a),
+            reservation_b: Some(b),
+        }))
+    }
+
+    /// patch the attributes of a reservation
+    async fn update_attributes(
+        &self,
+        request: Request<UpdateAttributesRequest>,
+    ) -> Result<Response<UpdateAttributesResponse>, Status> {
+        let request = request.into_inner();
+        let reservation = self
+            .manager
+            .update_attributes(
+                request.id,
+                request.set,
+                request.remove,
+                expected_version(request.expected_version),
+            )
+            .await?;
+        Ok(Response::new(UpdateAttributesResponse {
+            reservation: Some(reservation),
+        }))
+    }
+
+    /// cancel a reservation
+    async fn cancel(
+        &self,
+        request: Request<CancelRequest>,
+    ) -> Result<Response<CancelResponse>, Status> {
+        let request = request.into_inner();
+        let scope = SeriesScope::try_from(request.scope).unwrap_or(SeriesScope::Occurrence);
+        let reservations = self
+            .manager
+            .cancel_series(
+                request.id,
+                scope,
+                request.user_id,
+                expected_version(request.expected_version),
+            )
+            .await?;
+        Ok(Response::new(CancelResponse {
+            reservation: reservations.iter().find(|r| r.id == request.id).cloned(),
+            reservations,
+        }))
+    }
+
+    /// get a reservation by id
+    async fn get(&self, request: Request<GetRequest>) -> Result<Response<GetResponse>, Status> {
+        let request = request.into_inner();
+        let reservation = self.manager.get(request.id).await?;
+        Ok(Response::new(GetResponse {
+            reservation: Some(reservation),
+        }))
+    }
+
+    type queryStream = ReservationStream;
+
+    /// query reservations
+    async fn query(
+        &self,
+        request: Request<QueryRequest>,
+    ) -> Result<Response<Self::queryStream>, Status> {
+        let Some(query) = request.into_inner().query else {
+            return Err(Status::invalid_argument("missing query"));
+        };
+        let stream = self.manager.query_stream(query).await?;
+        Ok(Response::new(Box::pin(stream.map_err(Status::from))))
+    }
+
+    /// query a page of reservations
+    async fn query_page(
+        &self,
+        request: Request<QueryRequest>,
+    ) -> Result<Response<ReservationPage>, Status> {
+        let Some(query) = request.into_inner().query else {
+            return Err(Status::invalid_argument("missing query"));
+        };
+        let page = self.manager.query(query).await?;
+        Ok(Response::new(page))
+    }
+
+    /// find the free intervals of resources
+    async fn availability(
+        &self,
+        request: Request<AvailabilityRequest>,
+    ) -> Result<Response<AvailabilityResponse>, Status> {
+        let Some(query) = request.into_inner().query else {
+            return Err(Status::invalid_argument("missing query"));
+        };
+        let intervals = self.manager.availability(query).await?;
+        Ok(Response::new(AvailabilityResponse { intervals }))
+    }
+
+    /// find the available resources of a group
+    async fn find_available(
+        &self,
+        request: Request<FindAvailableRequest>,
+    ) -> Result<Response<FindAvailableResponse>, Status> {
+        let Some(query) = request.into_inner().query else {
+            return Err(Status::invalid_argument("missing query"));
+        };
+        let resource_ids = self.manager.find_available(query).await?;
+        Ok(Response::new(FindAvailableResponse { resource_ids }))
+    }
+
+    /// reserve the first available resource of a group
+    async fn reserve_any(
+        &self,
+        request: Request<ReserveAnyRequest>,
+    ) -> Result<Response<ReserveAnyResponse>, Status> {
+        let request = request.into_inner();
+        let (Some(group), Some(reservation)) = (request.group, request.reservation) else {
+            return Err(Status::invalid_argument("missing group or reservation"));
+        };
+        let reservation = reservation.with_hold_ttl(request.hold_ttl)?;
+        let reservation = self.manager.reserve_any(group, reservation).await?;
+        Ok(Response::new(ReserveAnyResponse {
+            reservation: Some(reservation),
+        }))
+    }
+
+    type subscribeStream = SubscribeStream;
+
+    /// subscribe to reservation updates
+    async fn subscribe(
+        &self,
+        request: Request<SubscribeRequest>,
+    ) -> Result<Response<Self::subscribeStream>, Status> {
+        let request = request.into_inner();
+        let stream = self.feed.subscribe(request.since_change_id);
+        Ok(Response::new(Box::pin(stream.map_err(Status::from))))
+    }
+
+    /// register a resource
+    async fn create_resource(
+        &self,
+        request: Request<CreateResourceRequest>,
+    ) -> Result<Response<CreateResourceResponse>, Status> {
+        let Some(resource) = request.into_inner().resource else {
+            return Err(Status::invalid_argument("missing resource"));
+        };
+        let resource = self.resources.create(resource).await?;
+        Ok(Response::new(CreateResourceResponse {
+            resource: Some(resource),
+        }))
+    }
+
+    /// get a resource by id
+    async fn get_resource(
+        &self,
+        request: Request<GetResourceRequest>,
+    ) -> Result<Response<GetResourceResponse>, Status> {
+        let request = request.into_inner();
+        let resource = self.resources.get(&request.id).await?;
+        Ok(Response::new(GetResourceResponse {
+            resource: Some(resource),
+        }))
+    }
+
+    /// update name, kind and attributes of a resource
+    async fn update_resource(
+        &self,
+        request: Request<UpdateResourceRequest>,
+    ) -> Result<Response<UpdateResourceResponse>, Status> {
+        let Some(resource) = request.into_inner().resource else {
+            return Err(Status::invalid_argument("missing resource"));
+        };
+        let resource = self.resources.update(resource).await?;
+        Ok(Response::new(UpdateResourceResponse {
+            resource: Some(resource),
+        }))
+    }
+
+    /// list resources, optionally of a single kind
+    async fn list_resources(
+        &self,
+        request: Request<ListResourcesRequest>,
+    ) -> Result<Response<ListResourcesResponse>, Status> {
+        let request = request.into_inner();
+        let kind = (!request.kind.is_empty()).then_some(request.kind);
+        let resources = self.resources.list(kind, request.include_inactive).await?;
+        Ok(Response::new(ListResourcesResponse { resources }))
+    }
+
+    /// stop a resource from being reserved
+    async fn deactivate_resource(
+        &self,
+        request: Request<DeactivateResourceRequest>,
+    ) -> Result<Response<DeactivateResourceResponse>, Status> {
+        let request = request.into_inner();
+        let resource = self.resources.deactivate(&request.id).await?;
+        Ok(Response::new(DeactivateResourceResponse {
+            resource: Some(resource),
+        }))
+    }
+}
+
+/// versions start at 1, so 0 means no version is expected
+fn expected_version(version: i64) -> Option<i64> {
+    (version != 0).then_some(version)
+}
+
+#[cfg(test)]
+mod tests {
+    use abi::{Reservation, ReservationQueryBuilder, ReservationStatus, ReservationUpdateType};
+    use chrono::{TimeDelta, Utc};
+    use futures::StreamExt;
+    use prost_types::Timestamp;
+    use sqlx::PgPool;
+    use tonic::Code;
+
+    use super::*;
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn rpc_reserve_and_confirm_should_work(pool: PgPool) {
+        let service = RsvpService::new(ReservationManager::new(pool))
+            .await
+            .unwrap();
+        let rsvp = make_shur_reservation(&service).await;
+        assert!(rsvp.id != 0);
+
+        let request = Request::new(ConfirmRequest {
+            id: rsvp.id,
+            expected_version: rsvp.version,
+        });
+        let rsvp = service
+            .confirm(request)
+            .await
+            .unwrap()
+            .into_inner()
+            .reservation
+            .unwrap();
+        assert_eq!(rsvp.status, ReservationStatus::Confirmed as i32);
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn rpc_get_missing_reservation_should_return_not_found(pool: PgPool) {
+        let service = RsvpService::new(ReservationManager::new(pool))
+            .await
+            .unwrap();
+        let status = service
+            .get(Request::new(GetRequest { id: 42 }))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::NotFound);
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn rpc_query_should_stream_reservations(pool: PgPool) {
+        let service = RsvpService::new(ReservationManager::new(pool))
+            .await
+            .unwrap();
+        let rsvp = make_shur_reservation(&service).await;
+
+        let query = ReservationQueryBuilder::default()
+            .user_id("shurid")
+            .start("2025-05-12T15:00:00-0700".parse::<Timestamp>().unwrap())
+            .end("2025-05-16T12:00:00-0700".parse::<Timestamp>().unwrap())
+            .status(ReservationStatus::Pending as i32)
+            .build()
+            .unwrap();
+        let request = Request::new(QueryRequest { query: Some(query) });
+        let stream = service.query(request).await.unwrap().into_inner();
+        let rsvps: Vec<_> = stream.collect().await;
+
+        assert_eq!(rsvps.len(), 1);
+        assert_eq!(rsvps[0].as_ref().unwrap(), &rsvp);
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn rpc_query_should_search_notes(pool: PgPool) {
+        let service = RsvpService::new(ReservationManager::new(pool))
+            .await
+            .unwrap();
+        let rsvp = make_shur_reservation(&service).await;
+
+        let query = |note_search: &str| {
+            ReservationQueryBuilder::default()
+                .start("2025-05-12T15:00:00-0700".parse::<Timestamp>().unwrap())
+                .end("2025-05-16T12:00:00-0700".parse::<Timestamp>().unwrap())
+                .note_search(note_search)
+                .build()
+                .unwrap()
+        };
+        let request = Request::new(QueryRequest {
+            query: Some(query("shur")),
+        });
+        let stream = service.query(request).await.unwrap().into_inner();
+        let rsvps: Vec<_> = stream.collect().await;
+        assert_eq!(rsvps.len(), 1);
+        assert_eq!(rsvps[0].as_ref().unwrap(), &rsvp);
+
+        let request = Request::new(QueryRequest {
+            query: Some(query("crib")),
+        });
+        let stream = service.query(request).await.unwrap().into_inner();
+        assert_eq!(stream.count().await, 0);
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn rpc_subscribe_should_receive_changes(pool: PgPool) {
+        let service = RsvpService::new(ReservationManager::new(pool))
+            .await
+            .unwrap();
+        let mut stream = service
+            .subscribe(Request::new(SubscribeRequest::default()))
+            .await
+            .unwrap()
+            .into_inner();
+
+        let rsvp = make_shur_reservation(&service).await;
+        let change = stream.next().await.unwrap().unwrap();
+        assert_eq!(change.op, ReservationUpdateType::Create as i32);
+        assert_eq!(change.reservation.unwrap(), rsvp);
+
+        // resume from the beginning of the change log
+        let request = Request::new(SubscribeRequest {
+            since_change_id: Some(0),
+        });
+        let mut stream = service.subscribe(request).await.unwrap().into_inner();
+        let replayed = stream.next().await.unwrap().unwrap();
+        assert_eq!(replayed.change_id, change.change_id);
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn rpc_reserve_with_hold_ttl_should_set_expiration(pool: PgPool) {
+        let service = RsvpService::new(ReservationManager::new(pool))
+            .await
+            .unwrap();
+        let rsvp = Reservation::new_pending(
+            "shurid",
+            "ocean-view-room-777",
+            "2025-05-13T15:00:00-0700".parse().unwrap(),
+            "2025-05-15T12:00:00-0700".parse().unwrap(),
+            "hold while paying",
+        );
+        let request = Request::new(ReserveRequest {
+            reservation: Some(rsvp),
+            hold_ttl: Some(prost_types::Duration {
+                seconds: 15 * 60,
+                nanos: 0,
+            }),
+        });
+        let rsvp = service
+            .reserve(request)
+            .await
+            .unwrap()
+            .into_inner()
+            .reservation
+            .unwrap();
+
+        let expires_at = rsvp.get_expires_at().unwrap();
+        assert!(expires_at > Utc::now() + TimeDelta::minutes(14));
+        assert!(expires_at <= Utc::now() + TimeDelta::minutes(15));
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn rpc_recurring_reservation_should_cancel_following(pool: PgPool) {
+        let service = RsvpService::new(ReservationManager::new(pool))
+            .await
+            .unwrap();
+        let rsvp = Reservation::new_pending(
+            "shurid",
+            "meeting-room-1",
+            "2025-06-03T10:00:00Z".parse().unwrap(),
+            "2025-06-03T10:30:00Z".parse().unwrap(),
+            "standup",
+        );
+        let request = Request::new(ReserveRecurringRequest {
+            reservation: Some(rsvp),
+            rrule: "FREQ=WEEKLY;BYDAY=TU;COUNT=12".to_string(),
+            allow_partial: false,
+            hold_ttl: None,
+        });
+        let response = service
+            .reserve_recurring(request)
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(response.reservations.len(), 12);
+        assert!(response.conflicts.is_empty());
+
+        let request = Request::new(CancelRequest {
+            id: response.reservations[9].id,
+            scope: SeriesScope::Following as i32,
+            expected_version: 0,
+            user_id: "shurid".to_string(),
+        });
+        let response = service.cancel(request).await.unwrap().into_inner();
+        assert_eq!(response.reservations.len(), 3);
+        let reservation = response.reservation.unwrap();
+        assert_eq!(reservation.status, ReservationStatus::Cancelled as i32);
+        assert_eq!(reservation.cancelled_by, "shurid");
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    async fn rpc_reserve_should_require_active_resource(pool: PgPool) {
+        let service = RsvpService::new(ReservationManager::new(pool))
+            .await
+            .unwrap();
+        let resource = abi::Resource::new("ocean-view-room-777", "Ocean view room", "room");
+        let request = Request::new(CreateResourceRequest {
+            resource: Some(resource.clone()),
+        });
+        let created = service
+            .create_resource(request)
+            .await
+            .unwrap()
+            .into_inner()
+            .resource
+            .unwrap();
+        assert_eq!(created, resource);
+        make_shur_reservation(&service).await;
+
+        let request = Request::new(DeactivateResourceRequest {
+            id: resource.id.clone(),
+        });
+        service.deactivate_resource(request).await.unwrap();
+        let request = Request::new(ReserveRequest {
+            reservation: Some(Reservation::new_pending(
+                "shurid",
+                "ocean-view-room-777",
+                "2025-06-13T15:00:00-0700".parse().unwrap(),
+                "2025-06-15T12:00:00-0700".parse().unwrap(),
+                "after deactivation",
+            )),
+            hold_ttl: None,
+        });
+        let status = service.reserve(request).await.unwrap_err();
+        assert_eq!(status.code(), Code::FailedPrecondition);
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn rpc_reschedule_should_move_reservation(pool: PgPool) {
+        let service = RsvpService::new(ReservationManager::new(pool))
+            .await
+            .unwrap();
+        let rsvp = make_shur_reservation(&service).await;
+
+        let start: Timestamp = "2025-05-20T15:00:00-0700".parse().unwrap();
+        let end: Timestamp = "2025-05-22T12:00:00-0700".parse().unwrap();
+        let request = Request::new(RescheduleRequest {
+            id: rsvp.id,
+            start: Some(start),
+            end: Some(end),
+            resource_id: "ocean-view-room-778".to_string(),
+            expected_version: rsvp.version,
+        });
+        let moved = service
+            .reschedule(request)
+            .await
+            .unwrap()
+            .into_inner()
+            .reservation
+            .unwrap();
+        assert_eq!(moved.id, rsvp.id);
+        assert_eq!(moved.resource_id, "ocean-view-room-778");
+        assert_eq!((moved.start, moved.end), (Some(start), Some(end)));
+
+        let request = Request::new(RescheduleRequest {
+            id: rsvp.id,
+            start: Some(start),
+            ..Default::default()
+        });
+        let status = service.reschedule(request).await.unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument);
+    }
+
+    #[sqlx::test(
+        migrations = "../migrations",
+        fixtures(path = "../fixtures", scripts("resources"))
+    )]
+    async fn rpc_update_with_stale_version_should_abort(pool: PgPool) {
+        let service = RsvpService::new(ReservationManager::new(pool))
+            .await
+            .unwrap();
+        let rsvp = make_shur_reservation(&service).await;
+
+        let update = |note: &str| {
+            Request::new(UpdateRequest {
+                id: rsvp.id,
+                note: note.to_string(),
+                expected_version: rsvp.version,
+                ..Default::default()
+            })
+        };
+        let updated = service.update(update("late check-in")).await.unwrap();
+        assert_eq!(
+            updated.into_inner().reservation.unwrap().version,
+            rsvp.version + 1
+        );
+
+        let status = service.update(update("needs crib")).await.unwrap_err();
+        assert_eq!(status.code(), Code::Aborted);
+    }
+
+    async fn make_shur_reservation(service: &RsvpService) -> Reservation {
+        let rsvp = Reservation::new_pending(
+            "shurid",
+            "ocean-view-room-777",
+            "2025-05-13T15:00:00-0700".parse().unwrap(),
+            "2025-05-15T12:00:00-0700".parse().unwrap(),
+            "this is shur's reservation",
+        );
+        let request = Request::new(ReserveRequest {
+            reservation: Some(rsvp),
+            hold_ttl: None,
+        });
+        service
+            .reserve(request)
+            .await
+            .unwrap()
+            .into_inner()
+            .reservation
+            .unwrap()
+    }
+}